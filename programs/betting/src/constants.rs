@@ -1,33 +1,105 @@
+use anchor_lang::prelude::*;
+
 pub const GLOBAL_STATE_SEED: &[u8] = b"GLOBAL_STATE_SEED";
 pub const USER_STATE_SEED: &[u8] = b"USER_STATE_SEED";
 pub const ARENA_STATE_SEED: &[u8] = b"ARENA_STATE_SEED";
 pub const USER_BET_SEED: &[u8] = b"USER_BET_SEED";
 
 pub const EIGHT_BOX_STATE_SEED: &[u8] = b"EIGHT_BOX_STATE_SEED";
+pub const EIGHT_BOX_CONFIG_SEED: &[u8] = b"EIGHT_BOX_CONFIG_SEED";
+
+pub const PERIOD_CONFIG_SEED: &[u8] = b"PERIOD_CONFIG_SEED";
+pub const PERIOD_STATE_SEED: &[u8] = b"PERIOD_STATE_SEED";
+pub const PERIOD_RESULT_SEED: &[u8] = b"PERIOD_RESULT_SEED";
+pub const PERIOD_LEADERBOARD_SEED: &[u8] = b"PERIOD_LEADERBOARD_SEED";
+
+// hour, day and week accounts written before period kinds, see states/legacy.rs
 pub const HOUR_STATE_SEED: &[u8] = b"HOUR_STATE_SEED";
 pub const DAY_STATE_SEED: &[u8] = b"DAY_STATE_SEED";
 pub const WEEK_STATE_SEED: &[u8] = b"WEEK_STATE_SEED";
-
 pub const HOUR_RESULT_SEED: &[u8] = b"HOUR_RESULT_SEED";
 pub const DAY_RESULT_SEED: &[u8] = b"DAY_RESULT_SEED";
 pub const WEEK_RESULT_SEED: &[u8] = b"WEEK_RESULT_SEED";
 
+pub const SEASON_SEED: &[u8] = b"SEASON_SEED";
+pub const SEASON_STATE_SEED: &[u8] = b"SEASON_STATE_SEED";
+pub const SEASON_LEADERBOARD_SEED: &[u8] = b"SEASON_LEADERBOARD_SEED";
+
 pub const FRAGMENT_MINTER_SEED: &[u8] = b"FRAGMENT_MINTER_SEED";
 pub const NFT_MINTER_SEED: &[u8] = b"NFT_MINTER_SEED";
 pub const BUNDLE_MINTER_SEED: &[u8] = b"BUNDLE_MINTER_SEED";
 
 pub const NFT_BUILD_STATE_SEED: &[u8] = b"NFT_BUILD_STATE_SEED";
 
+pub const FEE_SPLIT_SEED: &[u8] = b"FEE_SPLIT_SEED";
+pub const BET_MINT_SEED: &[u8] = b"BET_MINT_SEED";
+pub const UNWRAP_SEED: &[u8] = b"UNWRAP_SEED";
+pub const RANK_CLAIMS_SEED: &[u8] = b"RANK_CLAIMS_SEED";
+pub const VESTING_SEED: &[u8] = b"VESTING_SEED";
+
+// TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb
+pub const TOKEN_2022_PROGRAM_ID: Pubkey = Pubkey::new_from_array([
+    6, 221, 246, 225, 238, 117, 143, 222, 24, 66, 93, 188, 228, 108, 205, 218, 182, 26, 252, 77,
+    131, 185, 13, 39, 254, 189, 249, 40, 216, 161, 139, 252,
+]);
+// Token-2022 mint extension holding the transfer fee schedule
+pub const TRANSFER_FEE_CONFIG_EXTENSION: u16 = 1;
+
 pub const FEE_RATE_DENOMINATOR: u64 = 10000;
 pub const INITIAL_PLATFORM_FEE_RATE: u64 = 1000; // 10%
 pub const INITIAL_REF_FEE_RATE: u64 = 1000; // 10%
 
+pub const MAX_FEE_RECIPIENTS: usize = 5;
+
+// upper bounds for update_config
+pub const MAX_PLATFORM_FEE_RATE: u64 = 2000; // 20%
+pub const MAX_REF_FEE_RATE: u64 = 5000; // 50% of platform fee
+
+// feature bits of GlobalState.paused
+pub const PAUSE_BETTING: u8 = 1 << 0;
+pub const PAUSE_SETTLEMENT: u8 = 1 << 1;
+pub const PAUSE_CLAIMS: u8 = 1 << 2;
+pub const PAUSE_SHOP: u8 = 1 << 3;
+pub const PAUSE_BUNDLE_OPENING: u8 = 1 << 4;
+pub const PAUSE_ALL: u8 = PAUSE_BETTING | PAUSE_SETTLEMENT | PAUSE_CLAIMS | PAUSE_SHOP | PAUSE_BUNDLE_OPENING;
+
 // in seconds
 pub const ONE_HOUR: u64 = 60 * 60;
 pub const ONE_DAY: u64 = ONE_HOUR * 24;
 pub const ONE_WEEK: u64 = ONE_DAY * 7;
 pub const EIGHT_HOUR: u64 = ONE_HOUR * 8;
 
+pub const MAX_PERIOD_KINDS: usize = 8;
+pub const MAX_PERIOD_TIERS: usize = 16;
+pub const MIN_PERIOD_LENGTH: u64 = ONE_HOUR;
+pub const MAX_VESTING_DURATION: u64 = ONE_WEEK * 52;
+// results can be claimed for this long after publication, then closed
+pub const MIN_CLAIM_WINDOW: u64 = ONE_DAY;
+pub const MAX_CLAIM_WINDOW: u64 = ONE_WEEK * 52;
+// leaderboards keep the top of the last tier and are created by CPI, which caps accounts at 10KB
+pub const MAX_LEADERBOARD_SIZE: u16 = 250;
+// seasons span whole weeks, their leaderboards use the period layout under this kind
+pub const MIN_SEASON_WEEKS: u64 = 2;
+pub const SEASON_LEADERBOARD_KIND: u8 = u8::MAX;
+pub const SEASON_LEADERBOARD_SIZE: u16 = 100;
+pub const MAX_SEASON_TROPHIES: usize = 10;
+// trophy names get " #<rank>" appended, metaplex caps names at 32 and URIs at 200 bytes
+pub const MAX_SEASON_NAME_LEN: usize = 28;
+pub const MAX_SEASON_URI_LEN: usize = 200;
+// claimed bitmaps are created by CPI, which caps accounts at 10KB
+pub const MAX_RANK_LEAVES: u32 = 80_000;
+// one bit of EightBoxState::claimed_status per prize,
+// set_eight_box_prizes takes them all in one transaction
+pub const MAX_EIGHT_BOX_PRIZES: usize = 32;
+pub const MAX_EIGHT_BOX_FRAGMENTS: u64 = 10;
+
+pub const MIN_ARENA_DURATION: u64 = 60;
+pub const MAX_ARENA_DURATION: u64 = ONE_DAY;
+
+pub const INITIAL_REFUND_GRACE_PERIOD: u64 = ONE_DAY;
+pub const MIN_REFUND_GRACE_PERIOD: u64 = ONE_HOUR;
+pub const MAX_REFUND_GRACE_PERIOD: u64 = ONE_WEEK;
+
 pub const FRAGMENT_URIS: [&str; 9] = [
     "https://arweave.net/qcZGaJh-HVDnxs5GumIcrPjyXQV3Thgd24jBzCIswR0",
     "https://arweave.net/qcZGaJh-HVDnxs5GumIcrPjyXQV3Thgd24jBzCIswR0",
@@ -78,9 +150,30 @@ pub enum ArenaStatus {
     EndRatioBelow,
     EndSuccess,
     Cancelled,
+    // stalled arena, bettors pull their stakes with return_bet
+    Refunding,
+}
+
+// what the users of a period kind are ranked by
+pub enum Ranking {
+    Volume,
+    // realised PnL, published as a Merkle root
+    Profit,
+}
+
+// prize minted for a rank tier on top of its FEEL reward
+pub enum RankPrize {
+    None,
+    Bundle,
+    Nft,
 }
 
-pub const EIGHT_BOX_LIMITS: [u64; 4] = [20_000_000, 100_000_000, 400_000_000, 1000_000_000];
+// prize paid once the volume of an eight-hour box reaches its threshold
+pub enum EightBoxPrizeKind {
+    Bundle,
+    Feel,
+    Fragment,
+}
 // pub const BUNDLE_REPARTITION_RATE: [u64][u64] = [
 //     [20, 20, 20, 20, 4.8, 4.8, 4.8, 0.8]
 // ]
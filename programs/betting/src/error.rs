@@ -79,4 +79,106 @@ pub enum BettingError {
 
     #[msg("This action is not expected.")]
     UnexpectedAction,
+
+    #[msg("Account layout version is not supported, migrate the account first")]
+    UnsupportedAccountVersion,
+
+    #[msg("Account is already on the current layout")]
+    AlreadyMigrated,
+
+    #[msg("Config value is out of bounds")]
+    ConfigOutOfBounds,
+
+    #[msg("Betting is paused")]
+    BettingPaused,
+
+    #[msg("Arena settlement is paused")]
+    SettlementPaused,
+
+    #[msg("Reward claims are paused")]
+    ClaimsPaused,
+
+    #[msg("Shop is paused")]
+    ShopPaused,
+
+    #[msg("Bundle opening is paused")]
+    BundleOpeningPaused,
+
+    #[msg("Arena is not stalled yet")]
+    ArenaNotStalled,
+
+    #[msg("Escrow holds less than the outstanding liabilities")]
+    EscrowInsolvent,
+
+    #[msg("Bet mint is not enabled")]
+    BetMintDisabled,
+
+    #[msg("Token account does not belong to the user")]
+    IncorrectTokenAccount,
+
+    #[msg("Token program must be the token program or Token-2022")]
+    IncorrectTokenProgram,
+
+    #[msg("Period has not ended yet")]
+    PeriodNotEnded,
+
+    #[msg("Ranked leaf count is out of range")]
+    InvalidLeafCount,
+
+    #[msg("Result is published as a Merkle root, a proof is required")]
+    MissingMerkleProof,
+
+    #[msg("Merkle proof does not match the published root")]
+    InvalidMerkleProof,
+
+    #[msg("Claim exceeds the funded reward budget")]
+    RewardBudgetExceeded,
+
+    #[msg("Period kind is not defined")]
+    UnknownPeriodKind,
+
+    #[msg("Invalid period kind config")]
+    InvalidPeriodConfig,
+
+    #[msg("Account does not belong to the current period")]
+    IncorrectPeriod,
+
+    #[msg("Invalid season config")]
+    InvalidSeasonConfig,
+
+    #[msg("Season is not running")]
+    SeasonNotActive,
+
+    #[msg("Season has not ended yet")]
+    SeasonNotEnded,
+
+    #[msg("Season is already ended")]
+    SeasonAlreadyEnded,
+
+    #[msg("User has no trophy in this season")]
+    NoSeasonTrophy,
+
+    #[msg("Claim window of the result is over")]
+    ClaimWindowClosed,
+
+    #[msg("Result can still be claimed")]
+    ClaimWindowOpen,
+
+    #[msg("Payout exceeds the booked liabilities")]
+    LiabilityUnderflow,
+
+    #[msg("Price feed has no valid price")]
+    InvalidPrice,
+
+    #[msg("Bundle id is out of range")]
+    InvalidBundleId,
+
+    #[msg("Bet has no profit or loss to settle")]
+    NothingToSettle,
+
+    #[msg("Bet is already settled")]
+    AlreadySettled,
+
+    #[msg("Eight-hour box prizes can only be retuned or appended")]
+    EightBoxPrizeLocked,
 }
@@ -0,0 +1,300 @@
+use anchor_lang::prelude::*;
+
+use crate::states::{Config, EightBoxPrize, MerkleRoot, PeriodTier, Roles};
+
+#[event]
+pub struct ConfigUpdated {
+    pub old_config: Config,
+    pub new_config: Config,
+}
+
+#[event]
+pub struct AuthorityProposed {
+    pub authority: Pubkey,
+    pub pending_authority: Pubkey,
+}
+
+#[event]
+pub struct AuthorityTransferred {
+    pub old_authority: Pubkey,
+    pub new_authority: Pubkey,
+}
+
+#[event]
+pub struct RolesUpdated {
+    pub old_roles: Roles,
+    pub new_roles: Roles,
+}
+
+#[event]
+pub struct PauseUpdated {
+    pub old_paused: u8,
+    pub new_paused: u8,
+}
+
+#[event]
+pub struct RefundTriggered {
+    pub arena_id: u64,
+    pub user: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ArenaOpened {
+    pub arena_id: u64,
+    pub bet_mint: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ArenaStarted {
+    pub arena_id: u64,
+    pub locked_price: u64,
+    pub start_timestamp: u64,
+    pub duration: u64,
+}
+
+#[event]
+pub struct ArenaSettled {
+    pub arena_id: u64,
+    // ArenaStatus::EndSuccess or ArenaStatus::EndRatioBelow
+    pub status: u8,
+    pub locked_price: u64,
+    pub final_price: u64,
+    pub bet_result: u8,
+    pub up_amount: u64,
+    pub down_amount: u64,
+    // fee taken from the arena, the prize pool share included
+    pub fee_amount: u64,
+    // part of the fee paid into the prize pools of the current periods
+    pub pool_amount: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct ArenaCancelled {
+    pub arena_id: u64,
+    pub final_price: u64,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct BetPlaced {
+    pub arena_id: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub is_up: u8,
+    pub referrer: Pubkey,
+    pub timestamp: u64,
+}
+
+#[event]
+pub struct LossSettled {
+    pub arena_id: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct WinSettled {
+    pub arena_id: u64,
+    pub user: Pubkey,
+    pub reward: u64,
+    pub profit: i64,
+}
+
+#[event]
+pub struct RewardClaimed {
+    pub arena_id: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+    pub platform_fee: u64,
+    pub referral_fee: u64,
+}
+
+#[event]
+pub struct BetReturned {
+    pub arena_id: u64,
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ReferralRewardClaimed {
+    pub user: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct PeriodResultPublished {
+    // period kind id in PeriodConfig
+    pub kind: u8,
+    pub period: u64,
+    pub tiers: Vec<u64>,
+    pub rewards: Vec<u64>,
+    pub merkle_root: Option<MerkleRoot>,
+    pub budget: u64,
+    pub claim_deadline: u64,
+    pub pool_budget: u64,
+}
+
+#[event]
+pub struct RankRewardClaimed {
+    // period kind id in PeriodConfig
+    pub kind: u8,
+    pub period: u64,
+    pub user: Pubkey,
+    pub tier: u8,
+    pub reward: u64,
+    // bundle or nft minted as prize
+    pub prize_mint: Option<Pubkey>,
+    // vesting account holding the reward, none when paid at once
+    pub vesting: Option<Pubkey>,
+    // bet tokens paid from the prize pool
+    pub pool_reward: u64,
+}
+
+#[event]
+pub struct LegacyRankRewardClaimed {
+    // 0 hour, 1 day, 2 week
+    pub legacy_kind: u8,
+    pub period: u64,
+    pub user: Pubkey,
+    // none when the state ranked in no tier and was only closed
+    pub tier: Option<u8>,
+    pub reward: u64,
+    pub prize_mint: Option<Pubkey>,
+}
+
+#[event]
+pub struct VestedWithdrawn {
+    pub user: Pubkey,
+    pub vesting: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+}
+
+#[event]
+pub struct SeasonCreated {
+    pub season_id: u64,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub win_bonus: u64,
+    pub claim_window: u64,
+    pub name: String,
+    pub uri: String,
+}
+
+#[event]
+pub struct SeasonEnded {
+    pub season_id: u64,
+    pub winners: Vec<Pubkey>,
+    pub scores: Vec<u64>,
+    pub rewards: Vec<u64>,
+    pub budget: u64,
+    pub claim_deadline: u64,
+}
+
+#[event]
+pub struct SeasonTrophyClaimed {
+    pub season_id: u64,
+    pub user: Pubkey,
+    // 1-based
+    pub rank: u8,
+    pub reward: u64,
+    pub trophy_mint: Pubkey,
+}
+
+#[event]
+pub struct EightBoxClaimed {
+    pub box_id: u64,
+    pub user: Pubkey,
+    pub prize_id: u8,
+    // EightBoxPrizeKind, with the bundle id or fragment number and the FEEL or fragment amount
+    pub kind: u8,
+    pub id: u8,
+    pub amount: u64,
+    // bundle or fragment mint, none for FEEL
+    pub prize_mint: Option<Pubkey>,
+}
+
+#[event]
+pub struct EightBoxPrizesUpdated {
+    pub prizes: Vec<EightBoxPrize>,
+    pub claim_window: u64,
+}
+
+#[event]
+pub struct BundleBought {
+    pub user: Pubkey,
+    pub bundle_id: u8,
+    pub bundle_mint: Pubkey,
+    pub price: u64,
+    pub burnt: u64,
+}
+
+#[event]
+pub struct BundleOpened {
+    pub user: Pubkey,
+    pub bundle_id: u8,
+    pub bundle_mint: Pubkey,
+    // fragment numbers, 1 to 9
+    pub fragments: Vec<u8>,
+}
+
+#[event]
+pub struct NftBuilt {
+    pub user: Pubkey,
+    pub nft_mint: Pubkey,
+}
+
+#[event]
+pub struct NftBought {
+    pub user: Pubkey,
+    pub nft_mint: Pubkey,
+    pub price: u64,
+    pub burnt: u64,
+}
+
+#[event]
+pub struct FeeSplitUpdated {
+    pub recipients: Vec<Pubkey>,
+    pub shares: Vec<u64>,
+}
+
+#[event]
+pub struct PeriodKindUpdated {
+    pub kind: u8,
+    pub length: u64,
+    pub epoch_offset: u64,
+    pub is_enabled: u8,
+    pub ranking: u8,
+    pub claim_window: u64,
+    pub tiers: Vec<PeriodTier>,
+}
+
+#[event]
+pub struct PeriodVestingUpdated {
+    pub kind: u8,
+    pub duration: u64,
+    pub cliff: u64,
+}
+
+#[event]
+pub struct PeriodPoolUpdated {
+    pub kind: u8,
+    pub fee_rate: u16,
+}
+
+#[event]
+pub struct FeesDistributed {
+    pub total: u64,
+    pub recipients: Vec<Pubkey>,
+    pub amounts: Vec<u64>,
+}
+
+#[event]
+pub struct BetMintUpdated {
+    pub mint: Pubkey,
+    pub is_enabled: u8,
+}
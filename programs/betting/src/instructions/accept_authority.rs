@@ -0,0 +1,30 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct AcceptAuthority<'info> {
+    pub pending_authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        has_one = pending_authority @ BettingError::Unauthorized,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+}
+
+/// Second step of an authority transfer, signed by the proposed key.
+pub fn handler(ctx: Context<AcceptAuthority>) -> Result<()> {
+    let accts = ctx.accounts;
+    let old_authority = accts.global_state.authority;
+    accts.global_state.authority = accts.pending_authority.key();
+    accts.global_state.pending_authority = Pubkey::default();
+
+    emit!(AuthorityTransferred {
+        old_authority,
+        new_authority: accts.global_state.authority,
+    });
+    Ok(())
+}
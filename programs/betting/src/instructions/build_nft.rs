@@ -3,7 +3,7 @@ use anchor_lang::{
     solana_program::{program::invoke_signed, pubkey},
 };
 
-use crate::{constants::*, error::*, instructions::*, states::*, utils::*};
+use crate::{constants::*, error::*, events::*, instructions::*, states::*, utils::*};
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{self, Burn, Mint, MintTo, Token, TokenAccount, Transfer},
@@ -21,7 +21,8 @@ pub struct BuildNft<'info> {
 
     #[account(
         seeds = [GLOBAL_STATE_SEED],
-        bump
+        bump,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
@@ -29,7 +30,8 @@ pub struct BuildNft<'info> {
         mut,
         seeds = [NFT_BUILD_STATE_SEED, user.key().as_ref()],
         bump,
-        close = user
+        close = user,
+        constraint = is_current_layout(&nft_build_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub nft_build_state: Box<Account<'info, NftBuildState>>,
     
@@ -90,8 +92,14 @@ pub fn handler<'a, 'b, 'c, 'info>(
         accts.system_program.to_account_info(),
         accts.rent.to_account_info(),
         accts.global_state.treasury,
-        &crate::ID
+        &crate::ID,
+        NFT_NAME.to_string(),
+        NFT_URI.to_string(),
     )?;
 
+    emit!(NftBuilt {
+        user: accts.user.key(),
+        nft_mint: accts.nft_mint.key(),
+    });
     Ok(())
 }
@@ -12,7 +12,6 @@ use mpl_token_metadata::{
   state::{Metadata, TokenMetadataAccount},
   ID as MetadataProgramID,
 };
-use std::mem::size_of;
 
 #[warn(unused_doc_comments)]
 #[derive(Accounts)]
@@ -25,7 +24,7 @@ pub struct BurnFragments<'info> {
       seeds = [NFT_BUILD_STATE_SEED, user.key().as_ref()],
       bump,
       payer = user,
-      space = 8 + size_of::<NftBuildState>()
+      space = 8 + NftBuildState::LEN
   )]
   pub nft_build_state: Box<Account<'info, NftBuildState>>,
 
@@ -46,6 +45,7 @@ pub fn handler<'a, 'b, 'c, 'info>(
 ) -> Result<()> {
   
   let accts = ctx.accounts;
+  accts.nft_build_state.version = NftBuildState::VERSION;
   let iter = &mut ctx.remaining_accounts.iter();
   for i in 1..=9 {
 
@@ -3,10 +3,10 @@ use anchor_lang::{
     solana_program::{program::invoke_signed, pubkey},
 };
 
-use crate::{constants::*, error::*, instructions::*, states::*, utils::*};
+use crate::{constants::*, error::*, events::*, instructions::*, states::*, utils::*};
 use anchor_spl::{
     associated_token::AssociatedToken,
-    token::{self, Burn, Mint, MintTo, Token, TokenAccount, Transfer},
+    token::{self, Mint, MintTo, Token, TokenAccount},
 };
 use mpl_token_metadata::{
     state::{Metadata, TokenMetadataAccount},
@@ -22,7 +22,9 @@ pub struct BuyBundle<'info> {
     #[account(
         seeds = [GLOBAL_STATE_SEED],
         bump,
-        has_one = treasury
+        has_one = treasury,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion,
+        constraint = !global_state.is_paused(PAUSE_SHOP) @ BettingError::ShopPaused
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
     
@@ -53,22 +55,30 @@ pub struct BuyBundle<'info> {
     )]
     pub user_bundle_ata: Box<Account<'info, TokenAccount>>,
     
+    /// CHECK: user's ATA of feel_mint under feel_token_program
     #[account(
         mut,
-        associated_token::mint = feel_mint,
-        associated_token::authority = user,
+        address = associated_token_address(&user.key(), &feel_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
     )]
-    pub user_feel_ata: Box<Account<'info, TokenAccount>>,
+    pub user_feel_ata: AccountInfo<'info>,
 
+    /// CHECK: treasury's ATA of feel_mint under feel_token_program
     #[account(
         mut,
-        associated_token::mint = feel_mint,
-        associated_token::authority = treasury,
+        address = associated_token_address(&treasury.key(), &feel_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
     )]
-    pub feel_treasury_ata: Box<Account<'info, TokenAccount>>,
+    pub feel_treasury_ata: AccountInfo<'info>,
 
-    #[account(mut, address = global_state.rank_mint)]
-    pub feel_mint: Account<'info, Mint>,
+    /// CHECK: FEEL mint, owned by feel_token_program
+    #[account(
+        mut,
+        address = global_state.rank_mint,
+        owner = feel_token_program.key() @ BettingError::IncorrectMint
+    )]
+    pub feel_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022 of the FEEL mint
+    #[account(constraint = is_token_program(feel_token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub feel_token_program: AccountInfo<'info>,
 
     #[account(address = MetadataProgramID)]
     /// CHECK:
@@ -82,24 +92,24 @@ impl<'info> BuyBundle<'info> {
     fn validate(&self) -> Result<()> {
         Ok(())
     }
-    fn transfer_to_vault_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
-        CpiContext::new(
-            self.token_program.to_account_info(),
-            Transfer {
-                from: self.user_feel_ata.to_account_info(),
-                to: self.feel_treasury_ata.to_account_info(),
-                authority: self.user.to_account_info(),
-            },
+    fn transfer_to_vault(&self, amount: u64) -> Result<u64> {
+        transfer_checked(
+            self.feel_token_program.to_account_info(),
+            self.user_feel_ata.to_account_info(),
+            self.feel_mint.to_account_info(),
+            self.feel_treasury_ata.to_account_info(),
+            self.user.to_account_info(),
+            &[],
+            amount,
         )
     }
-    fn burn_feel_context(&self) -> CpiContext<'_, '_, '_, 'info, Burn<'info>> {
-        CpiContext::new(
-            self.token_program.to_account_info(),
-            Burn {
-                mint: self.feel_mint.to_account_info(),
-                from: self.user_feel_ata.to_account_info(),
-                authority: self.user.to_account_info(),
-            },
+    fn burn_feel(&self, amount: u64) -> Result<()> {
+        burn_checked(
+            self.feel_token_program.to_account_info(),
+            self.user_feel_ata.to_account_info(),
+            self.feel_mint.to_account_info(),
+            self.user.to_account_info(),
+            amount,
         )
     }
 }
@@ -112,7 +122,7 @@ pub fn handler<'a, 'b, 'c, 'info>(
     let current_time = Clock::get()?.unix_timestamp as u64;
     let accts = ctx.accounts;
     let price = BUNDLE_COST[bundle_id as usize].checked_mul(10u64.pow(
-        accts.feel_mint.decimals as u32
+        mint_decimals(&accts.feel_mint)? as u32
     )).unwrap();
 
     let burn_amount = price
@@ -122,11 +132,13 @@ pub fn handler<'a, 'b, 'c, 'info>(
         .unwrap();
 
     let mut transfer_amount = price;
+    let mut burnt = 0;
     if accts.user.key().ne(&accts.treasury.key()) {
       transfer_amount = price.checked_sub(burn_amount).unwrap();
-      token::burn(accts.burn_feel_context(), burn_amount);
+      accts.burn_feel(burn_amount)?;
+      burnt = burn_amount;
     }
-    token::transfer(accts.transfer_to_vault_context(), transfer_amount);  
+    accts.transfer_to_vault(transfer_amount)?;  
     
     mint_bundle(
         accts.bundle_mint.to_account_info(),
@@ -144,5 +156,12 @@ pub fn handler<'a, 'b, 'c, 'info>(
         bundle_id as usize
     )?;
 
+    emit!(BundleBought {
+        user: accts.user.key(),
+        bundle_id,
+        bundle_mint: accts.bundle_mint.key(),
+        price,
+        burnt,
+    });
     Ok(())
 }
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, error::*, instructions::*, states::*, utils::*};
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
 
 use anchor_spl::{
     associated_token::{self, AssociatedToken},
@@ -15,13 +15,14 @@ use std::mem::size_of;
 #[instruction(arena_id: u64)]
 pub struct CancelArena<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub operator: Signer<'info>,
 
     #[account(
         seeds = [GLOBAL_STATE_SEED],
         bump,
-        has_one = authority,
-        has_one = sol_pyth_account
+        has_one = operator,
+        has_one = sol_pyth_account,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
@@ -29,6 +30,7 @@ pub struct CancelArena<'info> {
         mut,
         seeds = [ARENA_STATE_SEED, &arena_id.to_le_bytes()],
         bump,
+        constraint = is_current_layout(&arena_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub arena_state: Box<Account<'info, ArenaState>>,
 
@@ -57,5 +59,10 @@ pub fn handler(ctx: Context<CancelArena>, arena_id: u64) -> Result<()> {
     // arena is cancelled.
     accts.arena_state.status = ArenaStatus::Cancelled as u8;
 
+    emit!(ArenaCancelled {
+        arena_id,
+        final_price: accts.arena_state.final_price,
+        timestamp: current_time,
+    });
     Ok(())
 }
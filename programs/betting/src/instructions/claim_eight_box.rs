@@ -1,16 +1,10 @@
-use anchor_lang::{
-    prelude::*,
-    solana_program::{program::invoke_signed, pubkey},
-};
-
-use crate::{constants::*, error::*, instructions::*, states::*, utils::*};
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{self, Mint, MintTo, Token, TokenAccount, Transfer},
-};
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_spl::{associated_token::AssociatedToken, token::Token};
 use mpl_token_metadata::ID as MetadataProgramId;
+use std::slice::Iter;
 
-#[warn(unused_doc_comments)]
 #[derive(Accounts)]
 #[instruction(box_id: u64)]
 pub struct ClaimEightBox<'info> {
@@ -19,17 +13,45 @@ pub struct ClaimEightBox<'info> {
 
     #[account(
       seeds = [GLOBAL_STATE_SEED],
-      bump
+      bump,
+      has_one = rank_mint,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion,
+      constraint = !global_state.is_paused(PAUSE_CLAIMS) @ BettingError::ClaimsPaused
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
+    #[account(
+      seeds = [EIGHT_BOX_CONFIG_SEED],
+      bump,
+      constraint = is_current_layout(&eight_box_config) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub eight_box_config: Box<Account<'info, EightBoxConfig>>,
+
     #[account(
       mut,
       seeds = [EIGHT_BOX_STATE_SEED, user.key().as_ref(), &box_id.to_le_bytes()],
-      bump
+      bump,
+      constraint = is_current_layout(&eight_box_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub eight_box_state: Box<Account<'info, EightBoxState>>,
 
+    /// CHECK: global_state's ATA of rank_mint under feel_token_program, pays FEEL prizes
+    #[account(
+      mut,
+      address = associated_token_address(&global_state.key(), &rank_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub feel_vault_ata: AccountInfo<'info>,
+
+    /// CHECK: user's ATA of rank_mint, created in handler for a FEEL prize
+    #[account(mut)]
+    pub user_feel_ata: AccountInfo<'info>,
+
+    /// CHECK: FEEL mint, owned by feel_token_program
+    #[account(owner = feel_token_program.key() @ BettingError::IncorrectMint)]
+    pub rank_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022 of the FEEL mint
+    #[account(constraint = is_token_program(feel_token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub feel_token_program: AccountInfo<'info>,
     pub token_program: Program<'info, Token>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     #[account(address = MetadataProgramId)]
@@ -41,24 +63,125 @@ pub struct ClaimEightBox<'info> {
 
 impl<'info> ClaimEightBox<'info> {
     fn validate(&self, prize_id: u8) -> Result<()> {
-        // prize_id will be 0, 1, 2, 3
-        require!(prize_id <= 3, BettingError::InvalidParameter);
-        // is able to claim prize?
+        self.validate_window()?;
+        let prize = self.eight_box_config.prize(prize_id)?;
         require!(
-            self.eight_box_state.bet_amount >= EIGHT_BOX_LIMITS[prize_id as usize],
+            self.eight_box_state.bet_amount >= prize.volume,
             BettingError::UnableToClaim
         );
-        // is already claimed?
-        // 2.pow(prize_id) = 1, 2, 4, 8
-        let prize_exp = 2u8.pow(prize_id as u32);
         require!(
-            self.eight_box_state.claimed_status & prize_exp == 0,
+            self.eight_box_state.claimed_status & (1 << prize_id) == 0,
             BettingError::AlreadyClaimed
         );
         Ok(())
     }
+
+    fn validate_window(&self) -> Result<()> {
+        require!(
+            self.eight_box_state.is_claimable(
+                &self.eight_box_config,
+                Clock::get()?.unix_timestamp as u64,
+            ),
+            BettingError::ClaimWindowClosed
+        );
+        Ok(())
+    }
+
+    /// unlocked prizes of the box that are not claimed yet
+    fn unclaimed_prizes(&self) -> Vec<u8> {
+        self.eight_box_config
+            .prizes()
+            .iter()
+            .enumerate()
+            .filter(|(prize_id, prize)| {
+                self.eight_box_state.bet_amount >= prize.volume
+                    && self.eight_box_state.claimed_status & (1 << prize_id) == 0
+            })
+            .map(|(prize_id, _)| prize_id as u8)
+            .collect()
+    }
+
+    /// pay prize `prize_id` and mark it claimed, returns the bundle or fragment mint
+    /// remaining accounts of a bundle: minter, mint, ata, metadata, edition
+    /// remaining accounts of fragments: mint, ata
+    fn claim_prize(
+        &mut self,
+        rem_accts: &mut Iter<AccountInfo<'info>>,
+        program_id: &Pubkey,
+        global_bump: u8,
+        prize_id: u8,
+    ) -> Result<(EightBoxPrize, Option<Pubkey>)> {
+        let prize = *self.eight_box_config.prize(prize_id)?;
+        self.eight_box_state.claimed_status |= 1 << prize_id;
+
+        let mut prize_mint = None;
+        if prize.kind == EightBoxPrizeKind::Bundle as u8 {
+            let bundle_minter = next_account_info(rem_accts)?;
+            let bundle_mint = next_account_info(rem_accts)?;
+            let bundle_ata = next_account_info(rem_accts)?;
+            let bundle_metadata = next_account_info(rem_accts)?;
+            let bundle_edition = next_account_info(rem_accts)?;
+            mint_bundle(
+                bundle_mint.to_account_info(),
+                bundle_ata.to_account_info(),
+                bundle_metadata.to_account_info(),
+                bundle_edition.to_account_info(),
+                bundle_minter.to_account_info(),
+                self.user.to_account_info(),
+                self.token_metadata_program.to_account_info(),
+                self.token_program.to_account_info(),
+                self.system_program.to_account_info(),
+                self.rent.to_account_info(),
+                self.global_state.treasury,
+                program_id,
+                prize.id as usize,
+            )?;
+            prize_mint = Some(bundle_mint.key());
+        } else if prize.kind == EightBoxPrizeKind::Fragment as u8 {
+            let fragment_mint = next_account_info(rem_accts)?;
+            let fragment_ata = next_account_info(rem_accts)?;
+            for _ in 0..prize.amount {
+                mint_fragment(
+                    self.user.to_account_info(),
+                    fragment_mint.to_account_info(),
+                    fragment_ata.to_account_info(),
+                    self.global_state.to_account_info(),
+                    global_bump,
+                    self.token_program.to_account_info(),
+                    self.associated_token_program.to_account_info(),
+                    self.system_program.to_account_info(),
+                    self.rent.to_account_info(),
+                    program_id,
+                    prize.id,
+                )?;
+            }
+            prize_mint = Some(fragment_mint.key());
+        } else {
+            create_ata_if_needed(
+                self.user.to_account_info(),
+                self.user_feel_ata.to_account_info(),
+                self.user.to_account_info(),
+                self.rank_mint.to_account_info(),
+                self.system_program.to_account_info(),
+                self.feel_token_program.to_account_info(),
+                self.rent.to_account_info(),
+            )?;
+            transfer_checked(
+                self.feel_token_program.to_account_info(),
+                self.feel_vault_ata.to_account_info(),
+                self.rank_mint.to_account_info(),
+                self.user_feel_ata.to_account_info(),
+                self.global_state.to_account_info(),
+                &[&[GLOBAL_STATE_SEED, &[global_bump]]],
+                prize.amount,
+            )?;
+        }
+        Ok((prize, prize_mint))
+    }
 }
 
+/// Claim an unlocked prize of an eight-hour box, as defined in EightBoxConfig.
+/// Prizes can be claimed during the window and until the config's claim window after it.
 #[access_control(ctx.accounts.validate(prize_id))]
 pub fn handler<'a, 'b, 'c, 'info>(
     ctx: Context<'a, 'b, 'c, 'info, ClaimEightBox<'info>>,
@@ -67,41 +190,48 @@ pub fn handler<'a, 'b, 'c, 'info>(
 ) -> Result<()> {
     let accts = ctx.accounts;
     let rem_accts = &mut ctx.remaining_accounts.iter();
+    let global_bump = *ctx.bumps.get("global_state").unwrap();
 
-    let mut bundle_id = 0;
-    if prize_id == 0 {
-      bundle_id = 0;
-    } else if prize_id == 1 {
-      bundle_id = 3;
-    } else if prize_id == 2 {
-      bundle_id = 4;
-    } else if prize_id == 3 {
-      bundle_id = 5;
-    }
+    let (prize, prize_mint) = accts.claim_prize(rem_accts, ctx.program_id, global_bump, prize_id)?;
+
+    emit!(EightBoxClaimed {
+        box_id,
+        user: accts.user.key(),
+        prize_id,
+        kind: prize.kind,
+        id: prize.id,
+        amount: prize.amount,
+        prize_mint,
+    });
 
-    let bundle_minter = next_account_info(rem_accts)?;
-    let bundle_mint = next_account_info(rem_accts)?;
-    let bundle_ata = next_account_info(rem_accts)?;
-    let bundle_metadata = next_account_info(rem_accts)?;
-    let bundle_edition = next_account_info(rem_accts)?;
-    mint_bundle(
-        bundle_mint.to_account_info(),
-        bundle_ata.to_account_info(),
-        bundle_metadata.to_account_info(),
-        bundle_edition.to_account_info(),
-        bundle_minter.to_account_info(),
-        accts.user.to_account_info(),
-        accts.token_metadata_program.to_account_info(),
-        accts.token_program.to_account_info(),
-        accts.system_program.to_account_info(),
-        accts.rent.to_account_info(),
-        accts.global_state.treasury,
-        ctx.program_id,
-        bundle_id,
-    )?;
-
-    accts.eight_box_state.claimed_status =
-        accts.eight_box_state.claimed_status | 2u8.pow(prize_id as u32);
+    Ok(())
+}
 
+/// Claim every unlocked prize of an eight-hour box that is not claimed yet.
+/// remaining accounts: those of each claimed prize, in prize order
+#[access_control(ctx.accounts.validate_window())]
+pub fn claim_all_handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ClaimEightBox<'info>>,
+    box_id: u64,
+) -> Result<()> {
+    let accts = ctx.accounts;
+    let rem_accts = &mut ctx.remaining_accounts.iter();
+    let global_bump = *ctx.bumps.get("global_state").unwrap();
+
+    let prize_ids = accts.unclaimed_prizes();
+    require!(!prize_ids.is_empty(), BettingError::UnableToClaim);
+    for prize_id in prize_ids {
+        let (prize, prize_mint) =
+            accts.claim_prize(rem_accts, ctx.program_id, global_bump, prize_id)?;
+        emit!(EightBoxClaimed {
+            box_id,
+            user: accts.user.key(),
+            prize_id,
+            kind: prize.kind,
+            id: prize.id,
+            amount: prize.amount,
+            prize_mint,
+        });
+    }
     Ok(())
 }
@@ -0,0 +1,177 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, events::*, states::{legacy::*, *}, utils::*};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+};
+use mpl_token_metadata::ID as MetadataProgramId;
+
+#[derive(Accounts)]
+#[instruction(legacy_kind: u8, period: u64)]
+pub struct ClaimLegacyRankReward<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+      seeds = [GLOBAL_STATE_SEED],
+      bump,
+      has_one = rank_mint,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion,
+      constraint = !global_state.is_paused(PAUSE_CLAIMS) @ BettingError::ClaimsPaused
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// CHECK: global_state's ATA of rank_mint under feel_token_program, legacy rewards were paid from it
+    #[account(
+      mut,
+      address = associated_token_address(&global_state.key(), &rank_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub feel_vault_ata: AccountInfo<'info>,
+
+    /// CHECK: user's HourState, DayState or WeekState, seeds and layout checked in handler
+    #[account(mut, owner = crate::ID)]
+    pub legacy_state: AccountInfo<'info>,
+
+    /// CHECK: HourResult, DayResult or WeekResult of the period, seeds and layout checked in handler
+    #[account(owner = crate::ID)]
+    pub legacy_result: AccountInfo<'info>,
+
+    /// CHECK: user's ATA of rank_mint, created in handler if missing
+    #[account(mut)]
+    pub user_feel_ata: AccountInfo<'info>,
+
+    /// CHECK: FEEL mint, owned by feel_token_program
+    #[account(owner = feel_token_program.key() @ BettingError::IncorrectMint)]
+    pub rank_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022 of the FEEL mint
+    #[account(constraint = is_token_program(feel_token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub feel_token_program: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = MetadataProgramId)]
+    /// CHECK:
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ClaimLegacyRankReward<'info> {
+    fn validate(&self, legacy_kind: u8, period: u64, program_id: &Pubkey) -> Result<()> {
+        let legacy = LEGACY_PERIODS
+            .get(legacy_kind as usize)
+            .ok_or(BettingError::UnknownPeriodKind)?;
+        let (state_key, _) = Pubkey::find_program_address(
+            &[legacy.state_seed, self.user.key.as_ref(), &period.to_le_bytes()],
+            program_id,
+        );
+        require_keys_eq!(self.legacy_state.key(), state_key, BettingError::IncorrectPeriod);
+        let (result_key, _) = Pubkey::find_program_address(
+            &[legacy.result_seed, &period.to_le_bytes()],
+            program_id,
+        );
+        require_keys_eq!(self.legacy_result.key(), result_key, BettingError::IncorrectPeriod);
+        Ok(())
+    }
+}
+
+/// Claim the reward of an hour, day or week state written before period kinds,
+/// the way the baseline program paid it: FEEL from the shared vault and the prize of the tier.
+/// The state is closed back to the user, also when it ranked in no tier.
+/// remaining accounts for a prize: minter, mint, ata, metadata, edition
+#[access_control(ctx.accounts.validate(legacy_kind, period, ctx.program_id))]
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ClaimLegacyRankReward<'info>>,
+    legacy_kind: u8,
+    period: u64,
+) -> Result<()> {
+    let accts = ctx.accounts;
+    let rem_accts = &mut ctx.remaining_accounts.iter();
+    let legacy = &LEGACY_PERIODS[legacy_kind as usize];
+
+    let state = legacy.state(&accts.legacy_state.try_borrow_data()?)?;
+    require_keys_eq!(state.user, accts.user.key(), BettingError::IncorrectPeriod);
+    require!(state.is_claimed == 0, BettingError::AlreadyClaimed);
+    let rank = legacy.reward(&accts.legacy_result.try_borrow_data()?, state.bet_amount)?;
+
+    let mut received = 0;
+    let mut prize_mint = None;
+    if let Some((position, reward_amount)) = rank {
+        let signer_seeds = &[
+            GLOBAL_STATE_SEED,
+            &[*(ctx.bumps.get("global_state").unwrap())],
+        ];
+        create_ata_if_needed(
+            accts.user.to_account_info(),
+            accts.user_feel_ata.to_account_info(),
+            accts.user.to_account_info(),
+            accts.rank_mint.to_account_info(),
+            accts.system_program.to_account_info(),
+            accts.feel_token_program.to_account_info(),
+            accts.rent.to_account_info(),
+        )?;
+        received = transfer_checked(
+            accts.feel_token_program.to_account_info(),
+            accts.feel_vault_ata.to_account_info(),
+            accts.rank_mint.to_account_info(),
+            accts.user_feel_ata.to_account_info(),
+            accts.global_state.to_account_info(),
+            &[signer_seeds],
+            reward_amount,
+        )?;
+
+        if let Some((prize, bundle_id)) = legacy.prize(position) {
+            let minter = next_account_info(rem_accts)?;
+            let mint = next_account_info(rem_accts)?;
+            let ata = next_account_info(rem_accts)?;
+            let metadata = next_account_info(rem_accts)?;
+            let edition = next_account_info(rem_accts)?;
+            if prize == RankPrize::Nft as u8 {
+                mint_nft(
+                    mint.to_account_info(),
+                    ata.to_account_info(),
+                    metadata.to_account_info(),
+                    edition.to_account_info(),
+                    minter.to_account_info(),
+                    accts.user.to_account_info(),
+                    accts.token_metadata_program.to_account_info(),
+                    accts.token_program.to_account_info(),
+                    accts.system_program.to_account_info(),
+                    accts.rent.to_account_info(),
+                    accts.global_state.treasury,
+                    ctx.program_id,
+                    NFT_NAME.to_string(),
+                    NFT_URI.to_string(),
+                )?;
+            } else {
+                mint_bundle(
+                    mint.to_account_info(),
+                    ata.to_account_info(),
+                    metadata.to_account_info(),
+                    edition.to_account_info(),
+                    minter.to_account_info(),
+                    accts.user.to_account_info(),
+                    accts.token_metadata_program.to_account_info(),
+                    accts.token_program.to_account_info(),
+                    accts.system_program.to_account_info(),
+                    accts.rent.to_account_info(),
+                    accts.global_state.treasury,
+                    ctx.program_id,
+                    bundle_id,
+                )?;
+            }
+            prize_mint = Some(mint.key());
+        }
+    }
+    close_program_account(&accts.legacy_state, &accts.user.to_account_info())?;
+
+    emit!(LegacyRankRewardClaimed {
+        legacy_kind,
+        period,
+        user: accts.user.key(),
+        tier: rank.map(|(position, _)| position as u8),
+        reward: received,
+        prize_mint,
+    });
+    Ok(())
+}
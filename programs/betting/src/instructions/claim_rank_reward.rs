@@ -0,0 +1,389 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::Token,
+};
+use mpl_token_metadata::ID as MetadataProgramId;
+
+#[derive(Accounts)]
+#[instruction(kind: u8, period: u64)]
+pub struct ClaimRankReward<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+      seeds = [GLOBAL_STATE_SEED],
+      bump,
+      has_one = rank_mint,
+      constraint = global_state.token_mint == pool_mint.key() @ BettingError::IncorrectMint,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion,
+      constraint = !global_state.is_paused(PAUSE_CLAIMS) @ BettingError::ClaimsPaused
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+      mut,
+      seeds = [PERIOD_STATE_SEED, &[kind], user.key().as_ref(), &period.to_le_bytes()],
+      bump,
+      constraint = is_current_layout(&user_period_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub user_period_state: Box<Account<'info, PeriodState>>,
+
+    #[account(
+      mut,
+      seeds = [PERIOD_RESULT_SEED, &[kind], &period.to_le_bytes()],
+      bump,
+      constraint = is_current_layout(&period_result) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub period_result: Box<Account<'info, PeriodResult>>,
+
+    /// CHECK: period_result's ATA of rank_mint, holds the period budget
+    #[account(
+      mut,
+      address = associated_token_address(&period_result.key(), &rank_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub period_reward_vault: AccountInfo<'info>,
+
+    /// CHECK: zero copy leaderboard of the period, read in handler for claims without a proof
+    #[account(
+      seeds = [PERIOD_LEADERBOARD_SEED, &[kind], &period.to_le_bytes()],
+      bump
+    )]
+    pub period_leaderboard: AccountInfo<'info>,
+
+    #[account(
+      mut,
+      seeds = [RANK_CLAIMS_SEED, period_result.key().as_ref()],
+      bump,
+      constraint = rank_claims.version == RankClaims::VERSION @ BettingError::UnsupportedAccountVersion
+    )]
+    pub rank_claims: Box<Account<'info, RankClaims>>,
+
+    /// CHECK: user's ATA of rank_mint, created in handler if missing
+    #[account(mut)]
+    pub user_feel_ata: AccountInfo<'info>,
+
+    /// CHECK: Vesting of the claim, created in handler when the result vests
+    #[account(
+      mut,
+      seeds = [VESTING_SEED, period_result.key().as_ref(), user.key().as_ref()],
+      bump
+    )]
+    pub vesting: AccountInfo<'info>,
+
+    /// CHECK: vesting's ATA of rank_mint, created in handler when the result vests
+    #[account(
+      mut,
+      address = associated_token_address(&vesting.key(), &rank_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub vesting_vault: AccountInfo<'info>,
+
+    /// CHECK: period_result's ATA of pool_mint, holds the prize pool
+    #[account(
+      mut,
+      address = associated_token_address(&period_result.key(), &pool_mint.key(), pool_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub pool_vault: AccountInfo<'info>,
+
+    /// CHECK: user's ATA of pool_mint, created in handler when the tier has a pool payout
+    #[account(mut)]
+    pub user_pool_ata: AccountInfo<'info>,
+
+    /// CHECK: FEEL mint, owned by feel_token_program
+    #[account(owner = feel_token_program.key() @ BettingError::IncorrectMint)]
+    pub rank_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022 of the FEEL mint
+    #[account(constraint = is_token_program(feel_token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub feel_token_program: AccountInfo<'info>,
+    /// CHECK: default bet mint, owned by pool_token_program
+    #[account(owner = pool_token_program.key() @ BettingError::IncorrectMint)]
+    pub pool_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022 of the default bet mint
+    #[account(constraint = is_token_program(pool_token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub pool_token_program: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = MetadataProgramId)]
+    /// CHECK:
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ClaimRankReward<'info> {
+    fn validate(&self, proof: &Option<RankProof>) -> Result<()> {
+        require!(
+            self.user_period_state.is_claimed == 0,
+            BettingError::AlreadyClaimed
+        );
+        require!(
+            self.period_result
+                .is_claimable(Clock::get()?.unix_timestamp as u64),
+            BettingError::ClaimWindowClosed
+        );
+        // a profit ranking can only reward users who ended the period in profit
+        require!(
+            self.period_result.ranking != Ranking::Profit as u8
+                || self.user_period_state.pnl > 0,
+            BettingError::UnableToClaim
+        );
+        match proof {
+            Some(proof) => self.rank_claims.verify(
+                &self.period_result.merkle_root,
+                self.user.key,
+                proof,
+            ),
+            None => {
+                require!(
+                    self.period_result.merkle_root == [0; 32],
+                    BettingError::MissingMerkleProof
+                );
+                require!(
+                    self.period_result
+                        .volume_tier(self.user_period_state.bet_amount)
+                        .is_some(),
+                    BettingError::UnableToClaim
+                );
+                // tiers are funded for the board's entries, off-board ties are not ranked
+                require!(
+                    is_board_entry(&self.period_leaderboard, self.user.key)?,
+                    BettingError::UnableToClaim
+                );
+                Ok(())
+            }
+        }
+    }
+
+    /// tier and FEEL reward of the claim, from the proven leaf or the volume tiers,
+    /// booked against the period budget
+    fn reward(&mut self, proof: &Option<RankProof>) -> Result<(usize, u64)> {
+        let (position, reward) = match proof {
+            Some(proof) => {
+                self.rank_claims.set_claimed(proof.index);
+                (
+                    rank_tier(proof.rank, self.period_result.tier_config()),
+                    proof.reward,
+                )
+            }
+            None => {
+                let position = self
+                    .period_result
+                    .volume_tier(self.user_period_state.bet_amount)
+                    .unwrap();
+                (position, self.period_result.reward_per_tier[position])
+            }
+        };
+        self.period_result.claimed = self
+            .period_result
+            .claimed
+            .checked_add(reward)
+            .filter(|claimed| *claimed <= self.period_result.budget)
+            .ok_or(BettingError::RewardBudgetExceeded)?;
+        Ok((position, reward))
+    }
+
+    fn pay_feel(&self, signer_seeds: &[&[u8]], amount: u64) -> Result<u64> {
+        create_ata_if_needed(
+            self.user.to_account_info(),
+            self.user_feel_ata.to_account_info(),
+            self.user.to_account_info(),
+            self.rank_mint.to_account_info(),
+            self.system_program.to_account_info(),
+            self.feel_token_program.to_account_info(),
+            self.rent.to_account_info(),
+        )?;
+        transfer_checked(
+            self.feel_token_program.to_account_info(),
+            self.period_reward_vault.to_account_info(),
+            self.rank_mint.to_account_info(),
+            self.user_feel_ata.to_account_info(),
+            self.period_result.to_account_info(),
+            &[signer_seeds],
+            amount,
+        )
+    }
+
+    /// pay the pool payout of the tier, booked against the pool budget
+    fn pay_pool(&mut self, signer_seeds: &[&[u8]], position: usize) -> Result<u64> {
+        let amount = self.period_result.pool_reward(position);
+        if amount == 0 {
+            return Ok(0);
+        }
+        self.period_result.pool_claimed = self
+            .period_result
+            .pool_claimed
+            .checked_add(amount)
+            .filter(|claimed| *claimed <= self.period_result.pool_budget)
+            .ok_or(BettingError::RewardBudgetExceeded)?;
+        create_ata_if_needed(
+            self.user.to_account_info(),
+            self.user_pool_ata.to_account_info(),
+            self.user.to_account_info(),
+            self.pool_mint.to_account_info(),
+            self.system_program.to_account_info(),
+            self.pool_token_program.to_account_info(),
+            self.rent.to_account_info(),
+        )?;
+        transfer_checked(
+            self.pool_token_program.to_account_info(),
+            self.pool_vault.to_account_info(),
+            self.pool_mint.to_account_info(),
+            self.user_pool_ata.to_account_info(),
+            self.period_result.to_account_info(),
+            &[signer_seeds],
+            amount,
+        )
+    }
+
+    /// move the reward into a new vesting account instead of paying it out
+    fn lock_vesting(
+        &self,
+        signer_seeds: &[&[u8]],
+        vesting_seeds: &[&[u8]],
+        amount: u64,
+    ) -> Result<u64> {
+        create_pda_account(
+            self.user.to_account_info(),
+            self.vesting.to_account_info(),
+            self.system_program.to_account_info(),
+            8 + Vesting::LEN,
+            &crate::ID,
+            vesting_seeds,
+        )?;
+        create_ata_if_needed(
+            self.user.to_account_info(),
+            self.vesting_vault.to_account_info(),
+            self.vesting.to_account_info(),
+            self.rank_mint.to_account_info(),
+            self.system_program.to_account_info(),
+            self.feel_token_program.to_account_info(),
+            self.rent.to_account_info(),
+        )?;
+        let received = transfer_checked(
+            self.feel_token_program.to_account_info(),
+            self.period_reward_vault.to_account_info(),
+            self.rank_mint.to_account_info(),
+            self.vesting_vault.to_account_info(),
+            self.period_result.to_account_info(),
+            &[signer_seeds],
+            amount,
+        )?;
+
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let mut vesting: Account<Vesting> = Account::try_from_unchecked(&self.vesting)?;
+        vesting.version = Vesting::VERSION;
+        vesting.user = self.user.key();
+        vesting.period_result = self.period_result.key();
+        vesting.start_time = current_time;
+        vesting.cliff_time = current_time + self.period_result.vesting_cliff;
+        vesting.end_time = current_time + self.period_result.vesting_duration;
+        vesting.total = received;
+        vesting.exit(&crate::ID)?;
+        Ok(received)
+    }
+}
+
+/// Claim the FEEL reward of a ranked period and the prize of its tier.
+/// When the result vests, the reward goes to a vesting account released by withdraw_vested.
+/// The tier's payout from the prize pool is paid at once in the default bet mint.
+/// The period state stays open as the claim receipt, close_period_state frees it after the window.
+/// remaining accounts for a prize: minter, mint, ata, metadata, edition
+#[access_control(ctx.accounts.validate(&proof))]
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ClaimRankReward<'info>>,
+    kind: u8,
+    period: u64,
+    proof: Option<RankProof>,
+) -> Result<()> {
+    let accts = ctx.accounts;
+    let rem_accts = &mut ctx.remaining_accounts.iter();
+
+    let (position, reward_amount) = accts.reward(&proof)?;
+    let signer_seeds = &[
+        PERIOD_RESULT_SEED,
+        &[kind],
+        &period.to_le_bytes(),
+        &[*(ctx.bumps.get("period_result").unwrap())],
+    ];
+    let mut vesting = None;
+    let received = if accts.period_result.vesting_duration > 0 && reward_amount > 0 {
+        let result_key = accts.period_result.key();
+        let vesting_seeds = &[
+            VESTING_SEED,
+            result_key.as_ref(),
+            accts.user.key.as_ref(),
+            &[*(ctx.bumps.get("vesting").unwrap())],
+        ];
+        vesting = Some(accts.vesting.key());
+        accts.lock_vesting(signer_seeds, vesting_seeds, reward_amount)?
+    } else {
+        accts.pay_feel(signer_seeds, reward_amount)?
+    };
+    let pool_reward = accts.pay_pool(signer_seeds, position)?;
+
+    let tier = accts
+        .period_result
+        .tier_config()
+        .get(position)
+        .copied()
+        .unwrap_or_default();
+    let mut prize_mint = None;
+    if tier.prize != RankPrize::None as u8 {
+        let minter = next_account_info(rem_accts)?;
+        let mint = next_account_info(rem_accts)?;
+        let ata = next_account_info(rem_accts)?;
+        let metadata = next_account_info(rem_accts)?;
+        let edition = next_account_info(rem_accts)?;
+        if tier.prize == RankPrize::Nft as u8 {
+            mint_nft(
+                mint.to_account_info(),
+                ata.to_account_info(),
+                metadata.to_account_info(),
+                edition.to_account_info(),
+                minter.to_account_info(),
+                accts.user.to_account_info(),
+                accts.token_metadata_program.to_account_info(),
+                accts.token_program.to_account_info(),
+                accts.system_program.to_account_info(),
+                accts.rent.to_account_info(),
+                accts.global_state.treasury,
+                ctx.program_id,
+                NFT_NAME.to_string(),
+                NFT_URI.to_string(),
+            )?;
+        } else {
+            mint_bundle(
+                mint.to_account_info(),
+                ata.to_account_info(),
+                metadata.to_account_info(),
+                edition.to_account_info(),
+                minter.to_account_info(),
+                accts.user.to_account_info(),
+                accts.token_metadata_program.to_account_info(),
+                accts.token_program.to_account_info(),
+                accts.system_program.to_account_info(),
+                accts.rent.to_account_info(),
+                accts.global_state.treasury,
+                ctx.program_id,
+                tier.bundle_id as usize,
+            )?;
+        }
+        prize_mint = Some(mint.key());
+    }
+
+    accts.user_period_state.is_claimed = 1;
+
+    emit!(RankRewardClaimed {
+        kind,
+        period,
+        user: accts.user.key(),
+        tier: position as u8,
+        reward: received,
+        prize_mint,
+        vesting,
+        pool_reward,
+    });
+    Ok(())
+}
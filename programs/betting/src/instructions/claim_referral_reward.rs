@@ -1,10 +1,7 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, states::*};
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{self, Mint, Token, TokenAccount, Transfer},
-};
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_spl::associated_token::AssociatedToken;
 
 #[derive(Accounts)]
 pub struct ClaimReferralReward<'info> {
@@ -14,35 +11,46 @@ pub struct ClaimReferralReward<'info> {
     #[account(
         seeds = [GLOBAL_STATE_SEED],
         bump,
-        has_one = token_mint
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion,
+        constraint = !global_state.is_paused(PAUSE_CLAIMS) @ BettingError::ClaimsPaused
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
-    #[account(
-      mut,
-      associated_token::mint = token_mint,
-      associated_token::authority = user,
-    )]
-    pub user_ata: Box<Account<'info, TokenAccount>>,
+    /// CHECK: user's ATA of token_mint, the user wallet in SOL arenas. checked in pay_out
+    #[account(mut)]
+    pub user_ata: AccountInfo<'info>,
+
+    /// CHECK: [UNWRAP_SEED, user] PDA, only used in SOL arenas. checked in pay_out
+    #[account(mut)]
+    pub unwrap_account: AccountInfo<'info>,
 
     #[account(
       mut,
       seeds = [USER_STATE_SEED, user.key().as_ref()],
       bump,
-      has_one = user
+      has_one = user,
+      constraint = is_current_layout(&user_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub user_state: Account<'info, UserState>,
 
+    /// CHECK: user_state's ATA of token_mint, created in handler if missing
+    #[account(mut)]
+    pub user_vault_ata: AccountInfo<'info>,
+
+    // referral fees are paid in the mint of each arena
     #[account(
-        init_if_needed,
-        associated_token::mint = token_mint,
-        associated_token::authority = user_state,
-        payer = user
+        seeds = [BET_MINT_SEED, token_mint.key().as_ref()],
+        bump,
+        constraint = is_current_layout(&bet_mint) @ BettingError::UnsupportedAccountVersion
     )]
-    pub user_vault_ata: Box<Account<'info, TokenAccount>>,
+    pub bet_mint: Box<Account<'info, BetMint>>,
 
-    pub token_mint: Box<Account<'info, Mint>>,
-    pub token_program: Program<'info, Token>,
+    /// CHECK: owned by token_program
+    #[account(owner = token_program.key() @ BettingError::IncorrectMint)]
+    pub token_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022
+    #[account(constraint = is_token_program(token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub token_program: AccountInfo<'info>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 
     pub system_program: Program<'info, System>,
@@ -53,14 +61,20 @@ impl<'info> ClaimReferralReward<'info> {
     fn validate(&self) -> Result<()> {
         Ok(())
     }
-    fn claim_referral_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
-        CpiContext::new(
+    fn pay_referral_reward(&self, signer_seeds: &[&[u8]], amount: u64) -> Result<u64> {
+        pay_out(
+            self.user_vault_ata.to_account_info(),
+            self.user_state.to_account_info(),
+            signer_seeds,
+            self.user.to_account_info(),
+            self.user_ata.to_account_info(),
+            self.unwrap_account.to_account_info(),
+            self.token_mint.to_account_info(),
             self.token_program.to_account_info(),
-            Transfer {
-                from: self.user_vault_ata.to_account_info(),
-                to: self.user_ata.to_account_info(),
-                authority: self.user_state.to_account_info(),
-            },
+            self.system_program.to_account_info(),
+            self.rent.to_account_info(),
+            &crate::ID,
+            amount,
         )
     }
 }
@@ -74,12 +88,24 @@ pub fn handler(ctx: Context<ClaimReferralReward>) -> Result<()> {
         user_key.as_ref(),
         &[*(ctx.bumps.get("user_state").unwrap())],
     ];
-    // to freelancer
-    token::transfer(
-        accts.claim_referral_context().with_signer(&[signer_seeds]),
-        accts.user_vault_ata.amount,
+    create_ata_if_needed(
+        accts.user.to_account_info(),
+        accts.user_vault_ata.to_account_info(),
+        accts.user_state.to_account_info(),
+        accts.token_mint.to_account_info(),
+        accts.system_program.to_account_info(),
+        accts.token_program.to_account_info(),
+        accts.rent.to_account_info(),
     )?;
+    let amount = token_amount(&accts.user_vault_ata)?;
+    // to freelancer
+    let amount = accts.pay_referral_reward(signer_seeds, amount)?;
 
     accts.user_state.ref_reward = 0;
+
+    emit!(ReferralRewardClaimed {
+        user: user_key,
+        amount,
+    });
     Ok(())
 }
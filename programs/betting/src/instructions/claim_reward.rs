@@ -1,10 +1,8 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, error::*, states::*};
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{self, Mint, Token, TokenAccount, Transfer},
-};
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_spl::associated_token::AssociatedToken;
+use std::slice::Iter;
 
 #[derive(Accounts)]
 #[instruction(arena_id: u64)]
@@ -15,7 +13,8 @@ pub struct ClaimReward<'info> {
     #[account(
       seeds = [GLOBAL_STATE_SEED],
       bump,
-      has_one = token_mint
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion,
+      constraint = !global_state.is_paused(PAUSE_CLAIMS) @ BettingError::ClaimsPaused
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
@@ -23,6 +22,7 @@ pub struct ClaimReward<'info> {
       mut,
       seeds = [ARENA_STATE_SEED, &arena_id.to_le_bytes()],
       bump,
+      constraint = is_current_layout(&arena_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub arena_state: Account<'info, ArenaState>,
 
@@ -30,48 +30,70 @@ pub struct ClaimReward<'info> {
       mut,
       seeds = [USER_BET_SEED, user.key().as_ref(), &arena_id.to_le_bytes()],
       bump,
-      close = user
+      close = user,
+      constraint = is_current_layout(&user_bet_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub user_bet_state: Account<'info, UserBetState>,
 
-    #[account(
-      mut,
-      associated_token::mint = token_mint,
-      associated_token::authority = user
-    )]
-    pub user_ata: Account<'info, TokenAccount>,
+    /// CHECK: user's ATA of token_mint, the user wallet in SOL arenas. checked in pay_out
+    #[account(mut)]
+    pub user_ata: AccountInfo<'info>,
 
+    /// CHECK: [UNWRAP_SEED, user] PDA, only used in SOL arenas. checked in pay_out
+    #[account(mut)]
+    pub unwrap_account: AccountInfo<'info>,
+
+    /// CHECK: global_state's ATA of token_mint under token_program
     #[account(
       mut,
-      associated_token::mint = token_mint,
-      associated_token::authority = global_state,
+      address = associated_token_address(&global_state.key(), &token_mint.key(), token_program.key) @ BettingError::IncorrectTokenAccount
     )]
-    pub escrow_ata: Account<'info, TokenAccount>,
+    pub escrow_ata: AccountInfo<'info>,
 
     #[account(
         mut,
         seeds = [USER_STATE_SEED, user.key().as_ref()],
-        bump
+        bump,
+        constraint = is_current_layout(&user_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub user_state: Box<Account<'info, UserState>>,
 
     #[account(
         mut,
         seeds = [USER_STATE_SEED, user_state.referrer.as_ref()],
-        bump
+        bump,
+        constraint = is_current_layout(&ref_user_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub ref_user_state: Box<Account<'info, UserState>>,
 
+    /// CHECK: ref_user_state's ATA of token_mint, created in handler if missing
+    #[account(mut)]
+    pub ref_user_vault_ata: AccountInfo<'info>,
+
     #[account(
-        init_if_needed,
-        associated_token::mint = token_mint,
-        associated_token::authority = ref_user_state,
-        payer = user,
+      mut,
+      seeds = [BET_MINT_SEED, token_mint.key().as_ref()],
+      bump,
+      constraint = is_current_layout(&bet_mint) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub bet_mint: Box<Account<'info, BetMint>>,
+
+    #[account(
+      seeds = [PERIOD_CONFIG_SEED],
+      bump,
+      constraint = is_current_layout(&period_config) @ BettingError::UnsupportedAccountVersion
     )]
-    pub ref_user_vault_ata: Box<Account<'info, TokenAccount>>,
+    pub period_config: Box<Account<'info, PeriodConfig>>,
 
-    pub token_mint: Account<'info, Mint>,
-    pub token_program: Program<'info, Token>,
+    /// CHECK: mint of the arena, owned by token_program
+    #[account(
+      address = arena_state.mint_or(global_state.token_mint) @ BettingError::IncorrectMint,
+      owner = token_program.key() @ BettingError::IncorrectMint
+    )]
+    pub token_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022
+    #[account(constraint = is_token_program(token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub token_program: AccountInfo<'info>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
@@ -99,30 +121,84 @@ impl<'info> ClaimReward<'info> {
         );
         Ok(())
     }
-    fn claim_reward_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
-        CpiContext::new(
+    fn pay_reward(&self, signer_seeds: &[&[u8]], amount: u64) -> Result<u64> {
+        pay_out(
+            self.escrow_ata.to_account_info(),
+            self.global_state.to_account_info(),
+            signer_seeds,
+            self.user.to_account_info(),
+            self.user_ata.to_account_info(),
+            self.unwrap_account.to_account_info(),
+            self.token_mint.to_account_info(),
             self.token_program.to_account_info(),
-            Transfer {
-                from: self.escrow_ata.to_account_info(),
-                to: self.user_ata.to_account_info(),
-                authority: self.global_state.to_account_info(),
-            },
+            self.system_program.to_account_info(),
+            self.rent.to_account_info(),
+            &crate::ID,
+            amount,
         )
     }
-    fn take_referral_fee_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
-        CpiContext::new(
+    fn take_referral_fee(&self, signer_seeds: &[&[u8]], amount: u64) -> Result<u64> {
+        create_ata_if_needed(
+            self.user.to_account_info(),
+            self.ref_user_vault_ata.to_account_info(),
+            self.ref_user_state.to_account_info(),
+            self.token_mint.to_account_info(),
+            self.system_program.to_account_info(),
+            self.token_program.to_account_info(),
+            self.rent.to_account_info(),
+        )?;
+        transfer_checked(
             self.token_program.to_account_info(),
-            Transfer {
-                from: self.escrow_ata.to_account_info(),
-                to: self.ref_user_vault_ata.to_account_info(),
-                authority: self.global_state.to_account_info(),
-            },
+            self.escrow_ata.to_account_info(),
+            self.token_mint.to_account_info(),
+            self.ref_user_vault_ata.to_account_info(),
+            self.global_state.to_account_info(),
+            &[signer_seeds],
+            amount,
+        )
+    }
+    // the bet must have been placed while the season ran, and the season not ended yet
+    fn record_season_win(
+        &self,
+        rem_accts: &mut Iter<AccountInfo<'info>>,
+        program_id: &Pubkey,
+    ) -> Result<()> {
+        let season: Account<Season> = Account::try_from(next_account_info(rem_accts)?)?;
+        require!(
+            is_current_layout(&season),
+            BettingError::UnsupportedAccountVersion
+        );
+        require!(
+            season.is_running(self.user_bet_state.bet_timestamp),
+            BettingError::SeasonNotActive
+        );
+        let mut state: Account<SeasonState> = Account::try_from(next_account_info(rem_accts)?)?;
+        require!(
+            is_current_layout(&state),
+            BettingError::UnsupportedAccountVersion
+        );
+        require!(
+            state.season_id == season.season_id && state.user == self.user.key(),
+            BettingError::IncorrectPeriod
+        );
+        state.wins += 1;
+        state.exit(program_id)?;
+        season.record_score(
+            next_account_info(rem_accts)?,
+            program_id,
+            self.user.key(),
+            state.score(season.win_bonus),
         )
     }
 }
 
+/// remaining accounts: the user's period state of every profit ranked kind for the bet time,
+/// unless the bet was settled by settle_win, then to count the win towards a season: season, user season state, season leaderboard
 #[access_control(ctx.accounts.validate())]
-pub fn handler(ctx: Context<ClaimReward>, arena_id: u64) -> Result<()> {
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, ClaimReward<'info>>,
+    arena_id: u64,
+) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp as u64;
     let accts = ctx.accounts;
 
@@ -132,74 +208,99 @@ pub fn handler(ctx: Context<ClaimReward>, arena_id: u64) -> Result<()> {
             GLOBAL_STATE_SEED,
             &[*(ctx.bumps.get("global_state").unwrap())],
         ];
-        token::transfer(
-            accts.claim_reward_context().with_signer(&[signer_seeds]),
-            accts.user_bet_state.bet_amount,
-        )?;
+        let received = accts.pay_reward(signer_seeds, accts.user_bet_state.bet_amount)?;
         accts.user_bet_state.is_claimed = 1;
+        if accts.arena_state.is_booked == 1 {
+            accts.bet_mint.unclaimed_winnings = accts
+                .bet_mint
+                .unclaimed_winnings
+                .checked_sub(accts.user_bet_state.bet_amount)
+                .ok_or(BettingError::LiabilityUnderflow)?;
+        }
+        assert_solvent(&accts.escrow_ata, accts.bet_mint.liabilities())?;
+
+        emit!(RewardClaimed {
+            arena_id,
+            user: accts.user.key(),
+            amount: received,
+            platform_fee: 0,
+            referral_fee: 0,
+        });
         return Ok(());
     }
-    // total bet amount = up + down
+    // bets already settled by settle_win had their share and PnL booked there
+    let is_settled = accts.user_bet_state.is_settled == 1;
+    if !is_settled {
+        accts.arena_state.settle_winner(&mut accts.user_bet_state)?;
+    }
+    let user_real_reward = accts.user_bet_state.reward;
+    let ref_fee = accts.user_bet_state.referral_fee;
+
+    // the user's share of the pot before fees, for the event
+    let (_, winning_stakes) = accts.arena_state.winning_side();
     let bet_total_amount = accts
         .arena_state
         .up_amount
         .checked_add(accts.arena_state.down_amount)
         .unwrap();
-
-    // user reward = bet_total_amount * (mybetUp / totalbetInMySide)
-    let total_user_success_bet = if accts.arena_state.bet_result == 0 {
-        accts.arena_state.down_amount
-    } else {
-        accts.arena_state.up_amount
-    };
-
-    // user's reward
     let user_reward = u128::from(bet_total_amount)
         .checked_mul(accts.user_bet_state.bet_amount as u128)
         .unwrap()
-        .checked_div(total_user_success_bet as u128)
-        .unwrap();
-
-    // Fee for platform = user's reward * platformFeeRate
-    let platform_fee = user_reward
-        .checked_mul(accts.global_state.platform_fee_rate as u128)
-        .unwrap()
-        .checked_div(FEE_RATE_DENOMINATOR as u128)
-        .unwrap();
+        .checked_div(winning_stakes as u128)
+        .unwrap() as u64;
+    let platform_fee = user_reward.saturating_sub(user_real_reward);
 
-    // Referral Fee = Fee for platform * referralFeeRate
-    let ref_fee = platform_fee
-        .checked_mul(accts.global_state.referral_fee_rate as u128)
-        .unwrap()
-        .checked_div(FEE_RATE_DENOMINATOR as u128)
-        .unwrap();
+    let signer_seeds = &[
+        GLOBAL_STATE_SEED,
+        &[*(ctx.bumps.get("global_state").unwrap())],
+    ];
 
+    let received = accts.pay_reward(signer_seeds, user_real_reward)?;
+    let ref_received = accts.take_referral_fee(signer_seeds, ref_fee)?;
     accts.ref_user_state.ref_reward = accts
         .ref_user_state
         .ref_reward
-        .checked_add(ref_fee as u64)
+        .checked_add(ref_received)
         .unwrap();
 
-    // This is user's real reward which user will have had received
-    let user_real_reward = user_reward.checked_sub(platform_fee).unwrap() as u64;
-
-    let signer_seeds = &[
-        GLOBAL_STATE_SEED,
-        &[*(ctx.bumps.get("global_state").unwrap())],
-    ];
-
-    token::transfer(
-        accts.claim_reward_context().with_signer(&[signer_seeds]),
-        user_real_reward,
-    )?;
+    accts.user_bet_state.is_claimed = 1;
+    // winnings of arenas opened before the upgrade were never booked on the BetMint
+    if accts.arena_state.is_booked == 1 {
+        accts.bet_mint.unclaimed_winnings = accts
+            .bet_mint
+            .unclaimed_winnings
+            .checked_sub(user_real_reward)
+            .ok_or(BettingError::LiabilityUnderflow)?;
+        accts.bet_mint.unclaimed_referral_fees = accts
+            .bet_mint
+            .unclaimed_referral_fees
+            .checked_sub(ref_fee)
+            .ok_or(BettingError::LiabilityUnderflow)?;
+    }
+    assert_solvent(&accts.escrow_ata, accts.bet_mint.liabilities())?;
 
-    token::transfer(
-        accts
-            .take_referral_fee_context()
-            .with_signer(&[signer_seeds]),
-        ref_fee as u64,
-    )?;
+    let rem_accts = &mut ctx.remaining_accounts.iter();
+    if !is_settled {
+        let pnl = user_real_reward as i64 - accts.user_bet_state.bet_amount as i64;
+        record_pnl(
+            &accts.period_config,
+            rem_accts,
+            ctx.program_id,
+            accts.user.key,
+            accts.user_bet_state.bet_timestamp,
+            pnl,
+        )?;
+    }
+    if rem_accts.len() > 0 {
+        accts.record_season_win(rem_accts, ctx.program_id)?;
+    }
 
-    accts.user_bet_state.is_claimed = 1;
+    emit!(RewardClaimed {
+        arena_id,
+        user: accts.user.key(),
+        amount: received,
+        platform_fee,
+        referral_fee: ref_fee,
+    });
     Ok(())
 }
@@ -0,0 +1,164 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+use mpl_token_metadata::ID as MetadataProgramId;
+
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct ClaimSeasonTrophy<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+      seeds = [GLOBAL_STATE_SEED],
+      bump,
+      has_one = rank_mint,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion,
+      constraint = !global_state.is_paused(PAUSE_CLAIMS) @ BettingError::ClaimsPaused
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+      mut,
+      seeds = [SEASON_SEED, &season_id.to_le_bytes()],
+      bump,
+      constraint = is_current_layout(&season) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub season: Box<Account<'info, Season>>,
+
+    /// CHECK: season's ATA of rank_mint, holds the season budget
+    #[account(
+      mut,
+      address = associated_token_address(&season.key(), &rank_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub season_reward_vault: AccountInfo<'info>,
+
+    /// CHECK: user's ATA of rank_mint, created in handler if missing
+    #[account(mut)]
+    pub user_feel_ata: AccountInfo<'info>,
+
+    #[account(
+      seeds = [NFT_MINTER_SEED],
+      bump
+    )]
+    /// CHECK: safe
+    pub nft_creator: AccountInfo<'info>,
+
+    #[account(mut)]
+    pub nft_mint: Box<Account<'info, Mint>>,
+
+    #[account(mut)]
+    /// CHECK: safe
+    pub nft_metadata: AccountInfo<'info>,
+
+    #[account(mut)]
+    /// CHECK: safe
+    pub nft_edition: AccountInfo<'info>,
+
+    #[account(
+      mut,
+      associated_token::mint = nft_mint,
+      associated_token::authority = user,
+    )]
+    pub user_nft_ata: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: FEEL mint, owned by feel_token_program
+    #[account(owner = feel_token_program.key() @ BettingError::IncorrectMint)]
+    pub rank_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022 of the FEEL mint
+    #[account(constraint = is_token_program(feel_token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub feel_token_program: AccountInfo<'info>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    #[account(address = MetadataProgramId)]
+    /// CHECK:
+    pub token_metadata_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ClaimSeasonTrophy<'info> {
+    fn validate(&self) -> Result<()> {
+        require!(self.season.is_ended == 1, BettingError::SeasonNotEnded);
+        require!(
+            self.season.is_claimable(Clock::get()?.unix_timestamp as u64),
+            BettingError::ClaimWindowClosed
+        );
+        let rank = self
+            .season
+            .winner_rank(self.user.key)
+            .ok_or(BettingError::NoSeasonTrophy)?;
+        require!(!self.season.is_claimed(rank), BettingError::AlreadyClaimed);
+        Ok(())
+    }
+
+    fn pay_feel(&self, signer_seeds: &[&[u8]], amount: u64) -> Result<u64> {
+        create_ata_if_needed(
+            self.user.to_account_info(),
+            self.user_feel_ata.to_account_info(),
+            self.user.to_account_info(),
+            self.rank_mint.to_account_info(),
+            self.system_program.to_account_info(),
+            self.feel_token_program.to_account_info(),
+            self.rent.to_account_info(),
+        )?;
+        transfer_checked(
+            self.feel_token_program.to_account_info(),
+            self.season_reward_vault.to_account_info(),
+            self.rank_mint.to_account_info(),
+            self.user_feel_ata.to_account_info(),
+            self.season.to_account_info(),
+            &[signer_seeds],
+            amount,
+        )
+    }
+}
+
+/// Mint the season trophy of a top finisher and pay their FEEL reward from the season's vault,
+/// until the claim window of the season closes.
+#[access_control(ctx.accounts.validate())]
+pub fn handler(ctx: Context<ClaimSeasonTrophy>, season_id: u64) -> Result<()> {
+    let accts = ctx.accounts;
+    let rank = accts.season.winner_rank(accts.user.key).unwrap();
+    accts.season.claimed |= 1 << rank;
+    let reward = accts.season.rewards[rank];
+    accts.season.book_reward(reward)?;
+
+    let season_id_bytes = season_id.to_le_bytes();
+    let signer_seeds = &[
+        SEASON_SEED,
+        &season_id_bytes,
+        &[*(ctx.bumps.get("season").unwrap())],
+    ];
+    let received = accts.pay_feel(signer_seeds, reward)?;
+
+    mint_nft(
+        accts.nft_mint.to_account_info(),
+        accts.user_nft_ata.to_account_info(),
+        accts.nft_metadata.to_account_info(),
+        accts.nft_edition.to_account_info(),
+        accts.nft_creator.to_account_info(),
+        accts.user.to_account_info(),
+        accts.token_metadata_program.to_account_info(),
+        accts.token_program.to_account_info(),
+        accts.system_program.to_account_info(),
+        accts.rent.to_account_info(),
+        accts.global_state.treasury,
+        ctx.program_id,
+        accts.season.trophy_name(rank),
+        accts.season.uri.clone(),
+    )?;
+
+    emit!(SeasonTrophyClaimed {
+        season_id,
+        user: accts.user.key(),
+        rank: rank as u8 + 1,
+        reward: received,
+        trophy_mint: accts.nft_mint.key(),
+    });
+    Ok(())
+}
@@ -5,18 +5,19 @@ use crate::{constants::*, error::*, instructions::*, states::*, utils::*};
 #[derive(Accounts)]
 pub struct CloseArenaState<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub treasurer: Signer<'info>,
 
     #[account(
       seeds = [GLOBAL_STATE_SEED],
       bump,
-      has_one = authority
+      has_one = treasurer,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
     #[account(
       mut,
-      close = authority
+      close = treasurer
     )]
     pub arena_state: Box<Account<'info, ArenaState>>,
 
@@ -5,25 +5,51 @@ use crate::{constants::*, error::*, instructions::*, states::*, utils::*};
 #[derive(Accounts)]
 pub struct CloseEightBoxState<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub treasurer: Signer<'info>,
 
     #[account(
       seeds = [GLOBAL_STATE_SEED],
       bump,
-      has_one = authority
+      has_one = treasurer,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
     #[account(
       mut,
-      close = authority
+      seeds = [EIGHT_BOX_STATE_SEED, eight_box_state.user.as_ref(), &(eight_box_state.start_time / EIGHT_HOUR).to_le_bytes()],
+      bump,
+      close = treasurer
     )]
     pub eight_box_state: Box<Account<'info, EightBoxState>>,
 
+    #[account(
+      seeds = [EIGHT_BOX_CONFIG_SEED],
+      bump,
+      constraint = is_current_layout(&eight_box_config) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub eight_box_config: Box<Account<'info, EightBoxConfig>>,
+
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
 
+impl<'info> CloseEightBoxState<'info> {
+    fn validate(&self) -> Result<()> {
+        // users keep their prizes until the deadline
+        require!(
+            !self.eight_box_state.is_claimable(
+                &self.eight_box_config,
+                Clock::get()?.unix_timestamp as u64,
+            ),
+            BettingError::ClaimWindowOpen
+        );
+        Ok(())
+    }
+}
+
+/// Close an eight-hour box once its claim window is over.
+#[access_control(ctx.accounts.validate())]
 pub fn handler(
     ctx: Context<CloseEightBoxState>,
 ) -> Result<()> {
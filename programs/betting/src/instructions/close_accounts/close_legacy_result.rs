@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, states::{legacy::*, *}, utils::*};
+
+#[derive(Accounts)]
+#[instruction(legacy_kind: u8, period: u64)]
+pub struct CloseLegacyResult<'info> {
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
+
+    #[account(
+      seeds = [GLOBAL_STATE_SEED],
+      bump,
+      has_one = treasurer,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// CHECK: HourResult, DayResult or WeekResult of the period, seeds and layout checked in validate
+    #[account(mut, owner = crate::ID)]
+    pub legacy_result: AccountInfo<'info>,
+}
+
+impl<'info> CloseLegacyResult<'info> {
+    fn validate(&self, legacy_kind: u8, period: u64, program_id: &Pubkey) -> Result<()> {
+        let legacy = LEGACY_PERIODS
+            .get(legacy_kind as usize)
+            .ok_or(BettingError::UnknownPeriodKind)?;
+        let (result_key, _) = Pubkey::find_program_address(
+            &[legacy.result_seed, &period.to_le_bytes()],
+            program_id,
+        );
+        require_keys_eq!(self.legacy_result.key(), result_key, BettingError::IncorrectPeriod);
+        require!(
+            legacy.is_result(&self.legacy_result.try_borrow_data()?),
+            BettingError::UnsupportedAccountVersion
+        );
+        Ok(())
+    }
+}
+
+/// Close an hour, day or week result of the baseline program.
+/// Its rewards were paid from the shared FEEL vault, so there is nothing to sweep.
+#[access_control(ctx.accounts.validate(legacy_kind, period, ctx.program_id))]
+pub fn handler(ctx: Context<CloseLegacyResult>, legacy_kind: u8, period: u64) -> Result<()> {
+    let accts = ctx.accounts;
+    close_program_account(&accts.legacy_result, &accts.treasurer.to_account_info())
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, states::*, utils::*};
+
+#[derive(Accounts)]
+pub struct ClosePeriodLeaderboard<'info> {
+    /// CHECK: the board's payer, checked in handler
+    #[account(mut)]
+    pub payer: AccountInfo<'info>,
+
+    /// CHECK: zero copy leaderboard, parsed in handler
+    #[account(mut, owner = crate::ID)]
+    pub period_leaderboard: AccountInfo<'info>,
+
+    /// CHECK: result of the board's period, checked in handler
+    pub period_result: AccountInfo<'info>,
+
+    #[account(
+      seeds = [PERIOD_CONFIG_SEED],
+      bump,
+      constraint = is_current_layout(&period_config) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub period_config: Box<Account<'info, PeriodConfig>>,
+}
+
+/// Close a period leaderboard once the claim window of its result is over,
+/// the board is kept until then because claims without a proof must be on it,
+/// or once the kind's claim window after the period is over without a result.
+/// Anyone can close it, the rent goes back to the bettor who created it.
+pub fn handler(ctx: Context<ClosePeriodLeaderboard>) -> Result<()> {
+    let accts = ctx.accounts;
+    let (kind, period) = {
+        let data = accts.period_leaderboard.try_borrow_data()?;
+        let (board, _) = PeriodLeaderboard::from_data(&data)?;
+        require!(
+            board.version == PeriodLeaderboard::VERSION,
+            BettingError::UnsupportedAccountVersion
+        );
+        require_keys_eq!(accts.payer.key(), board.payer, BettingError::InvalidParameter);
+        (board.kind, board.period)
+    };
+    let (key, _) = Pubkey::find_program_address(
+        &[PERIOD_LEADERBOARD_SEED, &[kind], &period.to_le_bytes()],
+        ctx.program_id,
+    );
+    require_keys_eq!(accts.period_leaderboard.key(), key, BettingError::IncorrectPeriod);
+    let (result_key, _) = Pubkey::find_program_address(
+        &[PERIOD_RESULT_SEED, &[kind], &period.to_le_bytes()],
+        ctx.program_id,
+    );
+    require_keys_eq!(accts.period_result.key(), result_key, BettingError::IncorrectPeriod);
+
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    if *accts.period_result.owner == crate::ID {
+        let period_result: Account<PeriodResult> = Account::try_from(&accts.period_result)?;
+        require!(
+            !period_result.is_claimable(current_time),
+            BettingError::ClaimWindowOpen
+        );
+    } else {
+        let period_kind = accts.period_config.kind(kind)?;
+        let expiry = period_kind
+            .start_time(period + 1)
+            .saturating_add(period_kind.claim_window);
+        require!(current_time > expiry, BettingError::ClaimWindowOpen);
+    }
+    close_program_account(&accts.period_leaderboard, &accts.payer)
+}
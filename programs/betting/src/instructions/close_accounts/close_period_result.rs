@@ -0,0 +1,156 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, states::*, utils::*};
+
+#[derive(Accounts)]
+pub struct ClosePeriodResult<'info> {
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
+
+    #[account(
+      seeds = [GLOBAL_STATE_SEED],
+      bump,
+      has_one = treasurer,
+      has_one = rank_mint,
+      constraint = global_state.token_mint == pool_mint.key() @ BettingError::IncorrectMint,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// CHECK: global_state's ATA of rank_mint under feel_token_program
+    #[account(
+      mut,
+      address = associated_token_address(&global_state.key(), &rank_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub feel_vault_ata: AccountInfo<'info>,
+
+    #[account(
+      mut,
+      seeds = [PERIOD_RESULT_SEED, &[period_result.kind], &period_result.period.to_le_bytes()],
+      bump,
+      close = treasurer,
+      constraint = is_current_layout(&period_result) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub period_result: Box<Account<'info, PeriodResult>>,
+
+    /// CHECK: period_result's ATA of rank_mint, holds the unclaimed budget
+    #[account(
+      mut,
+      address = associated_token_address(&period_result.key(), &rank_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub period_reward_vault: AccountInfo<'info>,
+
+    #[account(
+      mut,
+      seeds = [RANK_CLAIMS_SEED, period_result.key().as_ref()],
+      bump,
+      close = treasurer
+    )]
+    pub rank_claims: Box<Account<'info, RankClaims>>,
+
+    #[account(
+      seeds = [FEE_SPLIT_SEED],
+      bump,
+      constraint = is_current_layout(&fee_split) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub fee_split: Box<Account<'info, FeeSplit>>,
+
+    /// CHECK: fee_split's ATA of pool_mint under pool_token_program
+    #[account(
+      mut,
+      address = associated_token_address(&fee_split.key(), &pool_mint.key(), pool_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub fee_vault_ata: AccountInfo<'info>,
+
+    /// CHECK: period_result's ATA of pool_mint, holds the unclaimed prize pool if it exists
+    #[account(
+      mut,
+      address = associated_token_address(&period_result.key(), &pool_mint.key(), pool_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub pool_vault: AccountInfo<'info>,
+
+    /// CHECK: FEEL mint, owned by feel_token_program
+    #[account(owner = feel_token_program.key() @ BettingError::IncorrectMint)]
+    pub rank_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022 of the FEEL mint
+    #[account(constraint = is_token_program(feel_token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub feel_token_program: AccountInfo<'info>,
+    /// CHECK: default bet mint, owned by pool_token_program
+    #[account(owner = pool_token_program.key() @ BettingError::IncorrectMint)]
+    pub pool_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022 of the default bet mint
+    #[account(constraint = is_token_program(pool_token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub pool_token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> ClosePeriodResult<'info> {
+    fn validate(&self) -> Result<()> {
+        // users keep their claims until the deadline
+        require!(
+            !self
+                .period_result
+                .is_claimable(Clock::get()?.unix_timestamp as u64),
+            BettingError::ClaimWindowOpen
+        );
+        Ok(())
+    }
+}
+
+/// Close a period result once its claim window is over,
+/// the unclaimed budget goes back to the shared FEEL vault and the unclaimed pool to the fee vault.
+#[access_control(ctx.accounts.validate())]
+pub fn handler(
+    ctx: Context<ClosePeriodResult>,
+) -> Result<()> {
+    let accts = ctx.accounts;
+    let signer_seeds = &[
+        PERIOD_RESULT_SEED,
+        &[accts.period_result.kind],
+        &accts.period_result.period.to_le_bytes(),
+        &[*(ctx.bumps.get("period_result").unwrap())],
+    ];
+    let unclaimed = token_amount(&accts.period_reward_vault)?;
+    if unclaimed > 0 {
+        transfer_checked(
+            accts.feel_token_program.to_account_info(),
+            accts.period_reward_vault.to_account_info(),
+            accts.rank_mint.to_account_info(),
+            accts.feel_vault_ata.to_account_info(),
+            accts.period_result.to_account_info(),
+            &[signer_seeds],
+            unclaimed,
+        )?;
+    }
+    close_token_account(
+        accts.feel_token_program.to_account_info(),
+        accts.period_reward_vault.to_account_info(),
+        accts.treasurer.to_account_info(),
+        accts.period_result.to_account_info(),
+        &[signer_seeds],
+    )?;
+
+    if !accts.pool_vault.data_is_empty() {
+        let unclaimed_pool = token_amount(&accts.pool_vault)?;
+        if unclaimed_pool > 0 {
+            transfer_checked(
+                accts.pool_token_program.to_account_info(),
+                accts.pool_vault.to_account_info(),
+                accts.pool_mint.to_account_info(),
+                accts.fee_vault_ata.to_account_info(),
+                accts.period_result.to_account_info(),
+                &[signer_seeds],
+                unclaimed_pool,
+            )?;
+        }
+        close_token_account(
+            accts.pool_token_program.to_account_info(),
+            accts.pool_vault.to_account_info(),
+            accts.treasurer.to_account_info(),
+            accts.period_result.to_account_info(),
+            &[signer_seeds],
+        )?;
+    }
+    Ok(())
+}
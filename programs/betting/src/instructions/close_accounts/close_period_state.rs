@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, states::*, utils::*};
+
+#[derive(Accounts)]
+pub struct ClosePeriodState<'info> {
+    /// CHECK: owner of the state, receives its rent
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+
+    #[account(
+      mut,
+      seeds = [PERIOD_STATE_SEED, &[user_period_state.kind], user.key().as_ref(), &user_period_state.period.to_le_bytes()],
+      bump,
+      close = user,
+      constraint = is_current_layout(&user_period_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub user_period_state: Box<Account<'info, PeriodState>>,
+
+    /// CHECK: result of the state's period, checked in handler, may not exist
+    #[account(
+      seeds = [PERIOD_RESULT_SEED, &[user_period_state.kind], &user_period_state.period.to_le_bytes()],
+      bump
+    )]
+    pub period_result: AccountInfo<'info>,
+
+    #[account(
+      seeds = [PERIOD_CONFIG_SEED],
+      bump,
+      constraint = is_current_layout(&period_config) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub period_config: Box<Account<'info, PeriodConfig>>,
+}
+
+impl<'info> ClosePeriodState<'info> {
+    fn validate(&self) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        // users keep their claims until the deadline
+        if *self.period_result.owner == crate::ID {
+            let period_result: Account<PeriodResult> = Account::try_from(&self.period_result)?;
+            require!(
+                !period_result.is_claimable(current_time),
+                BettingError::ClaimWindowOpen
+            );
+        } else {
+            let period_kind = self.period_config.kind(self.user_period_state.kind)?;
+            let expiry = period_kind
+                .start_time(self.user_period_state.period + 1)
+                .saturating_add(period_kind.claim_window);
+            require!(current_time > expiry, BettingError::ClaimWindowOpen);
+        }
+        Ok(())
+    }
+}
+
+/// Close a user's period state once the claim window of its result is over,
+/// or once the kind's claim window after the period is over without a result.
+/// Anyone can close it, the rent goes back to the user.
+#[access_control(ctx.accounts.validate())]
+pub fn handler(
+    ctx: Context<ClosePeriodState>,
+) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,113 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, states::*, utils::*};
+
+#[derive(Accounts)]
+pub struct CloseSeason<'info> {
+    #[account(mut)]
+    pub treasurer: Signer<'info>,
+
+    #[account(
+      seeds = [GLOBAL_STATE_SEED],
+      bump,
+      has_one = treasurer,
+      has_one = rank_mint,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    /// CHECK: global_state's ATA of rank_mint under feel_token_program
+    #[account(
+      mut,
+      address = associated_token_address(&global_state.key(), &rank_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub feel_vault_ata: AccountInfo<'info>,
+
+    #[account(
+      mut,
+      seeds = [SEASON_SEED, &season.season_id.to_le_bytes()],
+      bump,
+      close = treasurer,
+      constraint = is_current_layout(&season) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub season: Box<Account<'info, Season>>,
+
+    /// CHECK: season's ATA of rank_mint, holds the unclaimed budget
+    #[account(
+      mut,
+      address = associated_token_address(&season.key(), &rank_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub season_reward_vault: AccountInfo<'info>,
+
+    /// CHECK: zero copy leaderboard, its payer is checked in handler
+    #[account(
+      mut,
+      seeds = [SEASON_LEADERBOARD_SEED, &season.season_id.to_le_bytes()],
+      bump
+    )]
+    pub season_leaderboard: AccountInfo<'info>,
+
+    /// CHECK: the leaderboard's payer, checked in handler
+    #[account(mut)]
+    pub leaderboard_payer: AccountInfo<'info>,
+
+    /// CHECK: FEEL mint, owned by feel_token_program
+    #[account(owner = feel_token_program.key() @ BettingError::IncorrectMint)]
+    pub rank_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022 of the FEEL mint
+    #[account(constraint = is_token_program(feel_token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub feel_token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> CloseSeason<'info> {
+    fn validate(&self) -> Result<()> {
+        require!(self.season.is_ended == 1, BettingError::SeasonNotEnded);
+        // winners keep their claims until the deadline
+        require!(
+            !self.season.is_claimable(Clock::get()?.unix_timestamp as u64),
+            BettingError::ClaimWindowOpen
+        );
+        Ok(())
+    }
+}
+
+/// Close an ended season once its claim window is over,
+/// the unclaimed budget goes back to the shared FEEL vault
+/// and the leaderboard's rent to the authority that created it.
+#[access_control(ctx.accounts.validate())]
+pub fn handler(ctx: Context<CloseSeason>) -> Result<()> {
+    let accts = ctx.accounts;
+    let season_id_bytes = accts.season.season_id.to_le_bytes();
+    let signer_seeds = &[
+        SEASON_SEED,
+        &season_id_bytes,
+        &[*(ctx.bumps.get("season").unwrap())],
+    ];
+    let unclaimed = token_amount(&accts.season_reward_vault)?;
+    if unclaimed > 0 {
+        transfer_checked(
+            accts.feel_token_program.to_account_info(),
+            accts.season_reward_vault.to_account_info(),
+            accts.rank_mint.to_account_info(),
+            accts.feel_vault_ata.to_account_info(),
+            accts.season.to_account_info(),
+            &[signer_seeds],
+            unclaimed,
+        )?;
+    }
+    close_token_account(
+        accts.feel_token_program.to_account_info(),
+        accts.season_reward_vault.to_account_info(),
+        accts.treasurer.to_account_info(),
+        accts.season.to_account_info(),
+        &[signer_seeds],
+    )?;
+
+    {
+        let data = accts.season_leaderboard.try_borrow_data()?;
+        let (board, _) = PeriodLeaderboard::from_data(&data)?;
+        require_keys_eq!(accts.leaderboard_payer.key(), board.payer, BettingError::InvalidParameter);
+    }
+    close_program_account(&accts.season_leaderboard, &accts.leaderboard_payer)
+}
@@ -1,15 +1,21 @@
 
-pub mod close_hour_result;
-pub use close_hour_result::*;
-
-pub mod close_day_result;
-pub use close_day_result::*;
-
-pub mod close_week_result;
-pub use close_week_result::*;
+pub mod close_period_result;
+pub use close_period_result::*;
 
 pub mod close_arena_state;
 pub use close_arena_state::*;
 
 pub mod close_eight_box_state;
-pub use close_eight_box_state::*;
\ No newline at end of file
+pub use close_eight_box_state::*;
+
+pub mod close_legacy_result;
+pub use close_legacy_result::*;
+
+pub mod close_period_leaderboard;
+pub use close_period_leaderboard::*;
+
+pub mod close_season;
+pub use close_season::*;
+
+pub mod close_period_state;
+pub use close_period_state::*;
@@ -1,4 +1,4 @@
-use crate::{constants::*, error::*, states::*};
+use crate::{constants::*, error::*, states::*, utils::*};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -10,12 +10,13 @@ use std::mem::size_of;
 #[derive(Accounts)]
 pub struct CreateFragmentMints<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub minter: Signer<'info>,
 
     #[account(
         seeds = [GLOBAL_STATE_SEED],
         bump,
-        has_one = authority
+        has_one = minter,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
@@ -23,7 +24,7 @@ pub struct CreateFragmentMints<'info> {
         init,
         seeds = ["FRAGMENT1".as_ref()],
         bump,
-        payer = authority,
+        payer = minter,
         mint::decimals=6,
         mint::authority=global_state
     )]
@@ -32,7 +33,7 @@ pub struct CreateFragmentMints<'info> {
         init,
         seeds = ["FRAGMENT2".as_ref()],
         bump,
-        payer = authority,
+        payer = minter,
         mint::decimals=6,
         mint::authority=global_state
     )]
@@ -41,7 +42,7 @@ pub struct CreateFragmentMints<'info> {
         init,
         seeds = ["FRAGMENT3".as_ref()],
         bump,
-        payer = authority,
+        payer = minter,
         mint::decimals=6,
         mint::authority=global_state
     )]
@@ -50,7 +51,7 @@ pub struct CreateFragmentMints<'info> {
         init,
         seeds = ["FRAGMENT4".as_ref()],
         bump,
-        payer = authority,
+        payer = minter,
         mint::decimals=6,
         mint::authority=global_state
     )]
@@ -59,7 +60,7 @@ pub struct CreateFragmentMints<'info> {
         init,
         seeds = ["FRAGMENT5".as_ref()],
         bump,
-        payer = authority,
+        payer = minter,
         mint::decimals=6,
         mint::authority=global_state
     )]
@@ -68,7 +69,7 @@ pub struct CreateFragmentMints<'info> {
         init,
         seeds = ["FRAGMENT6".as_ref()],
         bump,
-        payer = authority,
+        payer = minter,
         mint::decimals=6,
         mint::authority=global_state
     )]
@@ -77,7 +78,7 @@ pub struct CreateFragmentMints<'info> {
         init,
         seeds = ["FRAGMENT7".as_ref()],
         bump,
-        payer = authority,
+        payer = minter,
         mint::decimals=6,
         mint::authority=global_state
     )]
@@ -86,7 +87,7 @@ pub struct CreateFragmentMints<'info> {
         init,
         seeds = ["FRAGMENT8".as_ref()],
         bump,
-        payer = authority,
+        payer = minter,
         mint::decimals=6,
         mint::authority=global_state
     )]
@@ -95,7 +96,7 @@ pub struct CreateFragmentMints<'info> {
         init,
         seeds = ["FRAGMENT9".as_ref()],
         bump,
-        payer = authority,
+        payer = minter,
         mint::decimals=6,
         mint::authority=global_state
     )]
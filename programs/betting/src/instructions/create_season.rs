@@ -0,0 +1,120 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct CreateSeason<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        has_one = authority,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        init,
+        seeds = [SEASON_SEED, &season_id.to_le_bytes()],
+        bump,
+        payer = authority,
+        space = 8 + Season::LEN
+    )]
+    pub season: Box<Account<'info, Season>>,
+
+    /// CHECK: zero copy leaderboard, created in handler
+    #[account(
+        mut,
+        seeds = [SEASON_LEADERBOARD_SEED, &season_id.to_le_bytes()],
+        bump
+    )]
+    pub season_leaderboard: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> CreateSeason<'info> {
+    fn validate(
+        &self,
+        start_time: u64,
+        end_time: u64,
+        claim_window: u64,
+        name: &str,
+        uri: &str,
+    ) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        let length = end_time.saturating_sub(start_time);
+        require!(
+            end_time > current_time
+                && length >= MIN_SEASON_WEEKS * ONE_WEEK
+                && length % ONE_WEEK == 0,
+            BettingError::InvalidSeasonConfig
+        );
+        require!(
+            (MIN_CLAIM_WINDOW..=MAX_CLAIM_WINDOW).contains(&claim_window),
+            BettingError::InvalidSeasonConfig
+        );
+        require!(
+            !name.is_empty() && name.len() <= MAX_SEASON_NAME_LEN && uri.len() <= MAX_SEASON_URI_LEN,
+            BettingError::InvalidSeasonConfig
+        );
+        Ok(())
+    }
+}
+
+/// Schedule a season over whole weeks, bets placed while it runs count towards it.
+/// Trophies can be claimed for `claim_window` seconds once the season is ended.
+#[access_control(ctx.accounts.validate(start_time, end_time, claim_window, &name, &uri))]
+pub fn handler(
+    ctx: Context<CreateSeason>,
+    season_id: u64,
+    start_time: u64,
+    end_time: u64,
+    win_bonus: u64,
+    claim_window: u64,
+    name: String,
+    uri: String,
+) -> Result<()> {
+    let accts = ctx.accounts;
+    init_pda_if_needed(
+        accts.authority.to_account_info(),
+        accts.season_leaderboard.to_account_info(),
+        accts.system_program.to_account_info(),
+        PeriodLeaderboard::space(SEASON_LEADERBOARD_SIZE as usize),
+        &[SEASON_LEADERBOARD_SEED, &season_id.to_le_bytes()],
+        ctx.program_id,
+    )?;
+    {
+        let mut data = accts.season_leaderboard.try_borrow_mut_data()?;
+        let (board, _) = PeriodLeaderboard::from_data_mut(&mut data, true)?;
+        board.version = PeriodLeaderboard::VERSION;
+        board.kind = SEASON_LEADERBOARD_KIND;
+        board.period = season_id;
+        board.capacity = SEASON_LEADERBOARD_SIZE as u64;
+        board.payer = accts.authority.key();
+    }
+
+    let season = &mut accts.season;
+    season.version = Season::VERSION;
+    season.season_id = season_id;
+    season.start_time = start_time;
+    season.end_time = end_time;
+    season.win_bonus = win_bonus;
+    season.claim_window = claim_window;
+    season.name = name.clone();
+    season.uri = uri.clone();
+
+    emit!(SeasonCreated {
+        season_id,
+        start_time,
+        end_time,
+        win_bonus,
+        claim_window,
+        name,
+        uri,
+    });
+    Ok(())
+}
@@ -0,0 +1,101 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct DistributeFees<'info> {
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        seeds = [FEE_SPLIT_SEED],
+        bump,
+        constraint = is_current_layout(&fee_split) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub fee_split: Box<Account<'info, FeeSplit>>,
+
+    /// CHECK: fee_split's ATA of token_mint under token_program
+    #[account(
+        mut,
+        address = associated_token_address(&fee_split.key(), &token_mint.key(), token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub fee_vault_ata: AccountInfo<'info>,
+
+    #[account(
+        seeds = [BET_MINT_SEED, token_mint.key().as_ref()],
+        bump,
+        constraint = is_current_layout(&bet_mint) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub bet_mint: Box<Account<'info, BetMint>>,
+
+    /// CHECK: owned by token_program
+    #[account(owner = token_program.key() @ BettingError::IncorrectMint)]
+    pub token_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022
+    #[account(constraint = is_token_program(token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub token_program: AccountInfo<'info>,
+}
+
+/// Pay out everything in the fee vault of one bet mint by the FeeSplit shares.
+/// Anyone can crank it, remaining accounts are the recipients' ATAs of the mint in FeeSplit order.
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, DistributeFees<'info>>,
+) -> Result<()> {
+    let accts = ctx.accounts;
+    let count = accts.fee_split.recipient_count as usize;
+    require!(
+        ctx.remaining_accounts.len() == count,
+        BettingError::InvalidParameter
+    );
+
+    let total = token_amount(&accts.fee_vault_ata)?;
+    let signer_seeds = &[
+        FEE_SPLIT_SEED,
+        &[*(ctx.bumps.get("fee_split").unwrap())],
+    ];
+
+    let mut amounts = Vec::with_capacity(count);
+    let mut paid = 0u64;
+    for (i, recipient) in ctx.remaining_accounts.iter().enumerate() {
+        require_keys_eq!(
+            recipient.key(),
+            associated_token_address(
+                &accts.fee_split.recipients[i],
+                accts.token_mint.key,
+                accts.token_program.key
+            ),
+            BettingError::InvalidParameter
+        );
+        // last recipient takes the rounding dust
+        let amount = if i + 1 == count {
+            total.checked_sub(paid).unwrap()
+        } else {
+            (total as u128)
+                .checked_mul(accts.fee_split.shares[i] as u128)
+                .unwrap()
+                .checked_div(FEE_RATE_DENOMINATOR as u128)
+                .unwrap() as u64
+        };
+        transfer_checked(
+            accts.token_program.to_account_info(),
+            accts.fee_vault_ata.to_account_info(),
+            accts.token_mint.to_account_info(),
+            recipient.to_account_info(),
+            accts.fee_split.to_account_info(),
+            &[signer_seeds],
+            amount,
+        )?;
+        paid = paid.checked_add(amount).unwrap();
+        amounts.push(amount);
+    }
+
+    emit!(FeesDistributed {
+        total,
+        recipients: accts.fee_split.recipients[..count].to_vec(),
+        amounts,
+    });
+    Ok(())
+}
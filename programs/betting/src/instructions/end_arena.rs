@@ -1,26 +1,24 @@
 use anchor_lang::prelude::*;
+use std::slice::Iter;
 
-use crate::{constants::*, error::*, states::*};
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
 
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{self, Mint, Token, TokenAccount, Transfer},
-};
+use anchor_spl::associated_token::AssociatedToken;
 
 use pyth_client;
 #[derive(Accounts)]
 #[instruction(arena_id: u64)]
 pub struct EndArena<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub operator: Signer<'info>,
 
     #[account(
         seeds = [GLOBAL_STATE_SEED],
         bump,
-        has_one = authority,
+        has_one = operator,
         has_one = sol_pyth_account,
-        has_one = treasury,
-        has_one = token_mint
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion,
+        constraint = !global_state.is_paused(PAUSE_SETTLEMENT) @ BettingError::SettlementPaused
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
@@ -28,32 +26,55 @@ pub struct EndArena<'info> {
         mut,
         seeds = [ARENA_STATE_SEED, &arena_id.to_le_bytes()],
         bump,
+        constraint = is_current_layout(&arena_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub arena_state: Box<Account<'info, ArenaState>>,
 
     /// CHECK:
     pub sol_pyth_account: AccountInfo<'info>,
 
-    /// CHECK:
-    pub treasury: AccountInfo<'info>,
+    #[account(
+        seeds = [FEE_SPLIT_SEED],
+        bump,
+        constraint = is_current_layout(&fee_split) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub fee_split: Box<Account<'info, FeeSplit>>,
+
+    #[account(
+        seeds = [PERIOD_CONFIG_SEED],
+        bump,
+        constraint = is_current_layout(&period_config) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub period_config: Box<Account<'info, PeriodConfig>>,
+
+    /// CHECK: fee_split's ATA of token_mint under token_program
+    #[account(
+        mut,
+        address = associated_token_address(&fee_split.key(), &token_mint.key(), token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub fee_vault_ata: AccountInfo<'info>,
+
+    /// CHECK: global_state's ATA of token_mint, created in handler if missing
+    #[account(mut)]
+    pub escrow_ata: AccountInfo<'info>,
 
     #[account(
-        init_if_needed,
-        associated_token::mint = token_mint,
-        associated_token::authority = treasury,
-        payer = authority
+        mut,
+        seeds = [BET_MINT_SEED, token_mint.key().as_ref()],
+        bump,
+        constraint = is_current_layout(&bet_mint) @ BettingError::UnsupportedAccountVersion
     )]
-    pub treasury_ata: Account<'info, TokenAccount>,
+    pub bet_mint: Box<Account<'info, BetMint>>,
 
+    /// CHECK: mint of the arena, owned by token_program
     #[account(
-        init_if_needed,
-        associated_token::mint = token_mint,
-        associated_token::authority = global_state,
-        payer = authority
+        address = arena_state.mint_or(global_state.token_mint) @ BettingError::IncorrectMint,
+        owner = token_program.key() @ BettingError::IncorrectMint
     )]
-    pub escrow_ata: Account<'info, TokenAccount>,
-    pub token_mint: Account<'info, Mint>,
-    pub token_program: Program<'info, Token>,
+    pub token_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022
+    #[account(constraint = is_token_program(token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub token_program: AccountInfo<'info>,
     pub associated_token_program: Program<'info, AssociatedToken>,
 
     pub system_program: Program<'info, System>,
@@ -69,23 +90,97 @@ impl<'info> EndArena<'info> {
         Ok(())
     }
     // CHECK: when take fee
-    fn to_treasury_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
-        CpiContext::new(
+    fn to_fee_vault(&self, signer_seeds: &[&[u8]], amount: u64) -> Result<u64> {
+        transfer_checked(
             self.token_program.to_account_info(),
-            Transfer {
-                from: self.escrow_ata.to_account_info(),
-                to: self.treasury_ata.to_account_info(),
-                authority: self.global_state.to_account_info(),
-            },
+            self.escrow_ata.to_account_info(),
+            self.token_mint.to_account_info(),
+            self.fee_vault_ata.to_account_info(),
+            self.global_state.to_account_info(),
+            &[signer_seeds],
+            amount,
         )
     }
+
+    /// pay the pool share of `fee` into the prize pool of each funded kind's current period,
+    /// only arenas in the default bet mint fund the pools
+    fn fund_prize_pools(
+        &self,
+        rem_accts: &mut Iter<AccountInfo<'info>>,
+        signer_seeds: &[&[u8]],
+        fee: u64,
+        timestamp: u64,
+    ) -> Result<u64> {
+        if self.token_mint.key() != self.global_state.token_mint {
+            return Ok(0);
+        }
+        let mut pooled = 0u64;
+        for (kind, period_kind) in self.period_config.pool_kinds() {
+            let period_result = next_account_info(rem_accts)?;
+            let pool_vault = next_account_info(rem_accts)?;
+            let period = period_kind.period_index(timestamp)?;
+            let (result_key, _) = Pubkey::find_program_address(
+                &[PERIOD_RESULT_SEED, &[kind], &period.to_le_bytes()],
+                &crate::ID,
+            );
+            require_keys_eq!(period_result.key(), result_key, BettingError::IncorrectPeriod);
+            require_keys_eq!(
+                pool_vault.key(),
+                associated_token_address(&result_key, self.token_mint.key, self.token_program.key),
+                BettingError::IncorrectTokenAccount
+            );
+            let amount = (fee as u128)
+                .checked_mul(period_kind.pool_fee_rate as u128)
+                .unwrap()
+                .checked_div(FEE_RATE_DENOMINATOR as u128)
+                .unwrap() as u64;
+            if amount == 0 {
+                continue;
+            }
+            create_ata_if_needed(
+                self.operator.to_account_info(),
+                pool_vault.to_account_info(),
+                period_result.to_account_info(),
+                self.token_mint.to_account_info(),
+                self.system_program.to_account_info(),
+                self.token_program.to_account_info(),
+                self.rent.to_account_info(),
+            )?;
+            transfer_checked(
+                self.token_program.to_account_info(),
+                self.escrow_ata.to_account_info(),
+                self.token_mint.to_account_info(),
+                pool_vault.to_account_info(),
+                self.global_state.to_account_info(),
+                &[signer_seeds],
+                amount,
+            )?;
+            pooled += amount;
+        }
+        Ok(pooled)
+    }
 }
 
+/// Settle an arena, the fee goes to the fee vault less the share of the prize pools.
+/// remaining accounts: [period_result, pool_vault] of the current period of each pool funded kind
 #[access_control(ctx.accounts.validate())]
-pub fn handler(ctx: Context<EndArena>, arena_id: u64) -> Result<()> {
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, EndArena<'info>>,
+    arena_id: u64,
+) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp as u64;
 
     let accts = ctx.accounts;
+    let rem_accts = &mut ctx.remaining_accounts.iter();
+    create_ata_if_needed(
+        accts.operator.to_account_info(),
+        accts.escrow_ata.to_account_info(),
+        accts.global_state.to_account_info(),
+        accts.token_mint.to_account_info(),
+        accts.system_program.to_account_info(),
+        accts.token_program.to_account_info(),
+        accts.rent.to_account_info(),
+    )?;
     let pyth_price_info = &accts.sol_pyth_account;
     let pyth_price_data = &pyth_price_info.try_borrow_data()?;
     let pyth_price = pyth_client::cast::<pyth_client::Price>(pyth_price_data);
@@ -103,10 +198,6 @@ pub fn handler(ctx: Context<EndArena>, arena_id: u64) -> Result<()> {
     if accts.arena_state.up_amount == 0 || accts.arena_state.down_amount == 0 {
         return Err(error!(BettingError::ArenaFailed));
     }
-    msg!("locked price = {:?}", accts.arena_state.locked_price);
-    msg!("final price = {:?}", accts.arena_state.final_price);
-    msg!("bet_result = {:?}", accts.arena_state.bet_result);
-
     let bet_total_amount = accts
         .arena_state
         .up_amount
@@ -129,7 +220,19 @@ pub fn handler(ctx: Context<EndArena>, arena_id: u64) -> Result<()> {
         accts.arena_state.up_amount
     };
 
+    // stakes of this arena become winnings owed to the winning side,
+    // arenas opened before the upgrade never booked theirs
+    let is_booked = accts.arena_state.is_booked == 1;
+    if is_booked {
+        accts.bet_mint.open_stakes = accts
+            .bet_mint
+            .open_stakes
+            .checked_sub(bet_total_amount)
+            .ok_or(BettingError::LiabilityUnderflow)?;
+    }
+
     // if winner ratio is < 1, basically betting is failed
+    let fee_amount;
     if expected_reward < total_user_success_bet {
         // total amount of failed bet
         let total_user_fail_bet = if accts.arena_state.bet_result == 1 {
@@ -138,39 +241,64 @@ pub fn handler(ctx: Context<EndArena>, arena_id: u64) -> Result<()> {
             accts.arena_state.up_amount
         };
 
-        // send to treasury
-        let signer_seeds = &[
-            GLOBAL_STATE_SEED,
-            &[*(ctx.bumps.get("global_state").unwrap())],
-        ];
-        token::transfer(
-            accts.to_treasury_context().with_signer(&[signer_seeds]),
-            total_user_fail_bet as u64,
-        )?;
+        // sent to fee vault
+        fee_amount = total_user_fail_bet;
+
+        // winners get their stakes back
+        accts.arena_state.winnings = total_user_success_bet;
+        if is_booked {
+            accts.bet_mint.unclaimed_winnings = accts
+                .bet_mint
+                .unclaimed_winnings
+                .checked_add(total_user_success_bet)
+                .unwrap();
+        }
 
         accts.arena_state.status = ArenaStatus::EndRatioBelow as u8;
     } else {
-        // Referral Fee = Fee for platform * referralFeeRate
-        let ref_fee = platform_fee
-            .checked_mul(accts.global_state.referral_fee_rate as u128)
-            .unwrap()
-            .checked_div(FEE_RATE_DENOMINATOR as u128)
-            .unwrap();
-
         // real platform fee = platform_fee - referal fee
-        let real_platform_fee = platform_fee.checked_sub(ref_fee).unwrap();
+        fee_amount = accts.arena_state.book_winnings(
+            accts.global_state.platform_fee_rate,
+            accts.global_state.referral_fee_rate,
+        );
 
-        let signer_seeds = &[
-            GLOBAL_STATE_SEED,
-            &[*(ctx.bumps.get("global_state").unwrap())],
-        ];
-        token::transfer(
-            accts.to_treasury_context().with_signer(&[signer_seeds]),
-            real_platform_fee as u64,
-        )?;
+        // referral fees stay in escrow until winners claim
+        if is_booked {
+            accts.bet_mint.unclaimed_winnings = accts
+                .bet_mint
+                .unclaimed_winnings
+                .checked_add(accts.arena_state.winnings)
+                .unwrap();
+            accts.bet_mint.unclaimed_referral_fees = accts
+                .bet_mint
+                .unclaimed_referral_fees
+                .checked_add(accts.arena_state.referral_fees)
+                .unwrap();
+        }
 
         accts.arena_state.status = ArenaStatus::EndSuccess as u8;
     }
 
+    let signer_seeds = &[
+        GLOBAL_STATE_SEED,
+        &[*(ctx.bumps.get("global_state").unwrap())],
+    ];
+    let pool_amount = accts.fund_prize_pools(rem_accts, signer_seeds, fee_amount, current_time)?;
+    accts.to_fee_vault(signer_seeds, fee_amount - pool_amount)?;
+
+    assert_solvent(&accts.escrow_ata, accts.bet_mint.liabilities())?;
+
+    emit!(ArenaSettled {
+        arena_id,
+        status: accts.arena_state.status,
+        locked_price: accts.arena_state.locked_price,
+        final_price: accts.arena_state.final_price,
+        bet_result: accts.arena_state.bet_result,
+        up_amount: accts.arena_state.up_amount,
+        down_amount: accts.arena_state.down_amount,
+        fee_amount,
+        pool_amount,
+        timestamp: current_time,
+    });
     Ok(())
 }
@@ -0,0 +1,260 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+
+#[derive(Accounts)]
+#[instruction(kind: u8, period: u64, rewards: Vec<u64>, merkle_root: Option<MerkleRoot>)]
+pub struct EndPeriod<'info> {
+    #[account(mut)]
+    pub ranker: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        has_one = ranker,
+        has_one = rank_mint,
+        constraint = global_state.token_mint == pool_mint.key() @ BettingError::IncorrectMint,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        seeds = [PERIOD_CONFIG_SEED],
+        bump,
+        constraint = is_current_layout(&period_config) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub period_config: Box<Account<'info, PeriodConfig>>,
+
+    /// CHECK: global_state's ATA of rank_mint under feel_token_program
+    #[account(
+      mut,
+      address = associated_token_address(&global_state.key(), &rank_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub feel_vault_ata: AccountInfo<'info>,
+
+    #[account(
+      init,
+      seeds = [PERIOD_RESULT_SEED, &[kind], &period.to_le_bytes()],
+      bump,
+      payer = ranker,
+      space = 8 + PeriodResult::LEN
+    )]
+    pub period_result: Box<Account<'info, PeriodResult>>,
+
+    /// CHECK: period_result's ATA of rank_mint, created in handler
+    #[account(mut)]
+    pub period_reward_vault: AccountInfo<'info>,
+
+    /// CHECK: zero copy header and entries are read in handler, unused for profit ranked kinds,
+    /// missing if nobody bet in the period
+    #[account(
+      seeds = [PERIOD_LEADERBOARD_SEED, &[kind], &period.to_le_bytes()],
+      bump
+    )]
+    pub period_leaderboard: AccountInfo<'info>,
+
+    #[account(
+      init,
+      seeds = [RANK_CLAIMS_SEED, period_result.key().as_ref()],
+      bump,
+      payer = ranker,
+      space = RankClaims::space(merkle_root.map_or(0, |merkle_root| merkle_root.leaf_count))
+    )]
+    pub rank_claims: Box<Account<'info, RankClaims>>,
+
+    /// CHECK: period_result's ATA of pool_mint, funded by end_arena, may not exist
+    #[account(
+      address = associated_token_address(&period_result.key(), &pool_mint.key(), pool_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub pool_vault: AccountInfo<'info>,
+
+    /// CHECK: FEEL mint, owned by feel_token_program
+    #[account(owner = feel_token_program.key() @ BettingError::IncorrectMint)]
+    pub rank_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022 of the FEEL mint
+    #[account(constraint = is_token_program(feel_token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub feel_token_program: AccountInfo<'info>,
+    /// CHECK: default bet mint, owned by pool_token_program
+    #[account(owner = pool_token_program.key() @ BettingError::IncorrectMint)]
+    pub pool_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022 of the default bet mint
+    #[account(constraint = is_token_program(pool_token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub pool_token_program: AccountInfo<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> EndPeriod<'info> {
+    fn validate(
+        &self,
+        kind: u8,
+        period: u64,
+        rewards: &[u64],
+        merkle_root: &Option<MerkleRoot>,
+    ) -> Result<()> {
+        let period_kind = self.period_config.kind(kind)?;
+        require!(
+            rewards.len() == period_kind.tier_count as usize,
+            BettingError::InvalidParameter
+        );
+        // profit rankings are computed off chain from the period states
+        require!(
+            merkle_root.is_some() || !period_kind.is_profit_ranked(),
+            BettingError::MissingMerkleProof
+        );
+        if let Some(merkle_root) = merkle_root {
+            require!(
+                merkle_root.leaf_count > 0 && merkle_root.leaf_count <= MAX_RANK_LEAVES,
+                BettingError::InvalidLeafCount
+            );
+        }
+        // nobody is ranked in a period without bets
+        require!(
+            merkle_root.is_none()
+                || period_kind.is_profit_ranked()
+                || !self.period_leaderboard.data_is_empty(),
+            BettingError::InvalidParameter
+        );
+        // the leaderboard is final once no more bets can land in the period
+        require!(
+            period_kind.current_period()? > period,
+            BettingError::PeriodNotEnded
+        );
+        Ok(())
+    }
+
+    /// bet tokens paid into the period's prize pool by end_arena
+    fn pool_budget(&self) -> Result<u64> {
+        if self.pool_vault.data_is_empty() {
+            return Ok(0);
+        }
+        token_amount(&self.pool_vault)
+    }
+
+    /// move `budget` FEEL from the shared vault into the result's own vault
+    fn lock_budget(&self, signer_seeds: &[&[u8]], budget: u64) -> Result<u64> {
+        create_ata_if_needed(
+            self.ranker.to_account_info(),
+            self.period_reward_vault.to_account_info(),
+            self.period_result.to_account_info(),
+            self.rank_mint.to_account_info(),
+            self.system_program.to_account_info(),
+            self.feel_token_program.to_account_info(),
+            self.rent.to_account_info(),
+        )?;
+        transfer_checked(
+            self.feel_token_program.to_account_info(),
+            self.feel_vault_ata.to_account_info(),
+            self.rank_mint.to_account_info(),
+            self.period_reward_vault.to_account_info(),
+            self.global_state.to_account_info(),
+            &[signer_seeds],
+            budget,
+        )
+    }
+}
+
+/// Publish a period result, tiers are the volumes at the tier ranks of the on-chain leaderboard.
+/// Users tied with a tier volume share that tier.
+/// With a Merkle root, rewards are claimed by exact rank with proofs against it instead,
+/// profit ranked kinds are always published this way once their losing bets are settled.
+/// The FEEL owed to the ranked users is locked in a vault of the result
+/// until the claim window of the kind closes.
+/// The prize pool is split between the tiers by their pool shares, a tier's share is divided
+/// among the users of the tier, or among its ranks for results published with a root.
+/// A period nobody bet in has no leaderboard and is published without tiers,
+/// with nothing to claim its claim window is over at once, so the pool can go back to the fee vault.
+#[access_control(ctx.accounts.validate(kind, period, &rewards, &merkle_root))]
+pub fn handler(
+    ctx: Context<EndPeriod>,
+    kind: u8,
+    period: u64,
+    rewards: Vec<u64>,
+    merkle_root: Option<MerkleRoot>,
+) -> Result<()> {
+    let period_kind = *ctx.accounts.period_config.kind(kind)?;
+    let tier_count = period_kind.tier_count as usize;
+    // nobody bet in the period
+    let is_empty =
+        !period_kind.is_profit_ranked() && ctx.accounts.period_leaderboard.data_is_empty();
+    let (tiers, budget, counts) = if period_kind.is_profit_ranked() {
+        // validated to be published with a root
+        let total_reward = merkle_root.map_or(0, |merkle_root| merkle_root.total_reward);
+        ([u64::MAX; MAX_PERIOD_TIERS], total_reward, tier_ranks(period_kind.tiers()))
+    } else if is_empty {
+        ([u64::MAX; MAX_PERIOD_TIERS], 0, [0; MAX_PERIOD_TIERS])
+    } else {
+        let data = ctx.accounts.period_leaderboard.try_borrow_data()?;
+        let (board, entries) = PeriodLeaderboard::from_data(&data)?;
+        require!(
+            board.version == PeriodLeaderboard::VERSION,
+            BettingError::UnsupportedAccountVersion
+        );
+        let entries = &entries[..board.count as usize];
+        let tiers = tier_thresholds(entries, period_kind.tiers());
+        let (budget, counts) = match merkle_root {
+            Some(merkle_root) => (merkle_root.total_reward, tier_ranks(period_kind.tiers())),
+            None => (
+                tier_budget(entries, &tiers[..tier_count], &rewards),
+                tier_counts(entries, &tiers[..tier_count]),
+            ),
+        };
+        (tiers, budget, counts)
+    };
+    let pool_budget = ctx.accounts.pool_budget()?;
+    let mut pool_per_tier = [0u64; MAX_PERIOD_TIERS];
+    for (position, tier) in period_kind.tiers().iter().enumerate() {
+        if counts[position] > 0 {
+            pool_per_tier[position] = ((pool_budget as u128)
+                .checked_mul(tier.pool_share as u128)
+                .unwrap()
+                / FEE_RATE_DENOMINATOR as u128
+                / counts[position] as u128) as u64;
+        }
+    }
+
+    let signer_seeds = &[
+        GLOBAL_STATE_SEED,
+        &[*(ctx.bumps.get("global_state").unwrap())],
+    ];
+    let budget = ctx.accounts.lock_budget(signer_seeds, budget)?;
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    let claim_deadline = if is_empty {
+        current_time
+    } else {
+        current_time + period_kind.claim_window
+    };
+
+    let result = &mut ctx.accounts.period_result;
+    result.version = PeriodResult::VERSION;
+    result.kind = kind;
+    result.period = period;
+    result.ranking = period_kind.ranking;
+    result.tier_count = period_kind.tier_count;
+    result.tier_config = period_kind.tiers;
+    result.tiers = tiers;
+    result.reward_per_tier[..tier_count].copy_from_slice(&rewards);
+    result.merkle_root = merkle_root.map_or([0; 32], |merkle_root| merkle_root.root);
+    result.budget = budget;
+    result.vesting_duration = period_kind.vesting_duration;
+    result.vesting_cliff = period_kind.vesting_cliff;
+    result.claim_deadline = claim_deadline;
+    result.pool_budget = pool_budget;
+    result.pool_per_tier = pool_per_tier;
+    ctx.accounts
+        .rank_claims
+        .init(merkle_root.map_or(0, |merkle_root| merkle_root.leaf_count));
+
+    emit!(PeriodResultPublished {
+        kind,
+        period,
+        tiers: tiers[..tier_count].to_vec(),
+        rewards,
+        merkle_root,
+        budget,
+        claim_deadline,
+        pool_budget,
+    });
+    Ok(())
+}
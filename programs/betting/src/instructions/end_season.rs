@@ -0,0 +1,146 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+
+#[derive(Accounts)]
+#[instruction(season_id: u64)]
+pub struct EndSeason<'info> {
+    #[account(mut)]
+    pub ranker: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        has_one = ranker,
+        has_one = rank_mint,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        mut,
+        seeds = [SEASON_SEED, &season_id.to_le_bytes()],
+        bump,
+        constraint = is_current_layout(&season) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub season: Box<Account<'info, Season>>,
+
+    /// CHECK: zero copy header and entries are read in handler
+    #[account(
+        seeds = [SEASON_LEADERBOARD_SEED, &season_id.to_le_bytes()],
+        bump
+    )]
+    pub season_leaderboard: AccountInfo<'info>,
+
+    /// CHECK: global_state's ATA of rank_mint under feel_token_program
+    #[account(
+      mut,
+      address = associated_token_address(&global_state.key(), &rank_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub feel_vault_ata: AccountInfo<'info>,
+
+    /// CHECK: season's ATA of rank_mint, created in handler
+    #[account(
+      mut,
+      address = associated_token_address(&season.key(), &rank_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub season_reward_vault: AccountInfo<'info>,
+
+    /// CHECK: FEEL mint, owned by feel_token_program
+    #[account(owner = feel_token_program.key() @ BettingError::IncorrectMint)]
+    pub rank_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022 of the FEEL mint
+    #[account(constraint = is_token_program(feel_token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub feel_token_program: AccountInfo<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> EndSeason<'info> {
+    fn validate(&self, rewards: &[u64]) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        require!(
+            current_time >= self.season.end_time,
+            BettingError::SeasonNotEnded
+        );
+        require!(self.season.is_ended == 0, BettingError::SeasonAlreadyEnded);
+        require!(
+            !rewards.is_empty() && rewards.len() <= MAX_SEASON_TROPHIES,
+            BettingError::InvalidParameter
+        );
+        Ok(())
+    }
+
+    /// move `budget` FEEL from the shared vault into the season's own vault
+    fn lock_budget(&self, signer_seeds: &[&[u8]], budget: u64) -> Result<u64> {
+        create_ata_if_needed(
+            self.ranker.to_account_info(),
+            self.season_reward_vault.to_account_info(),
+            self.season.to_account_info(),
+            self.rank_mint.to_account_info(),
+            self.system_program.to_account_info(),
+            self.feel_token_program.to_account_info(),
+            self.rent.to_account_info(),
+        )?;
+        transfer_checked(
+            self.feel_token_program.to_account_info(),
+            self.feel_vault_ata.to_account_info(),
+            self.rank_mint.to_account_info(),
+            self.season_reward_vault.to_account_info(),
+            self.global_state.to_account_info(),
+            &[signer_seeds],
+            budget,
+        )
+    }
+}
+
+/// Close the season standings, the top `rewards.len()` finishers win a trophy and their FEEL reward.
+/// Wins claimed after this no longer count.
+/// The FEEL owed to the winners is locked in a vault of the season
+/// until the season's claim window closes.
+#[access_control(ctx.accounts.validate(&rewards))]
+pub fn handler(ctx: Context<EndSeason>, season_id: u64, rewards: Vec<u64>) -> Result<()> {
+    let (winners, scores): (Vec<Pubkey>, Vec<u64>) = {
+        let data = ctx.accounts.season_leaderboard.try_borrow_data()?;
+        let (board, entries) = PeriodLeaderboard::from_data(&data)?;
+        require!(
+            board.version == PeriodLeaderboard::VERSION,
+            BettingError::UnsupportedAccountVersion
+        );
+        entries[..rewards.len().min(board.count as usize)]
+            .iter()
+            .map(|winner| (winner.user, winner.volume))
+            .unzip()
+    };
+    let rewards = &rewards[..winners.len()];
+    let budget = rewards
+        .iter()
+        .try_fold(0u64, |budget, reward| budget.checked_add(*reward))
+        .ok_or(BettingError::InvalidParameter)?;
+
+    let signer_seeds = &[
+        GLOBAL_STATE_SEED,
+        &[*(ctx.bumps.get("global_state").unwrap())],
+    ];
+    let budget = ctx.accounts.lock_budget(signer_seeds, budget)?;
+    let claim_deadline = Clock::get()?.unix_timestamp as u64 + ctx.accounts.season.claim_window;
+
+    let season = &mut ctx.accounts.season;
+    season.is_ended = 1;
+    season.winner_count = winners.len() as u8;
+    season.winners[..winners.len()].copy_from_slice(&winners);
+    season.rewards[..winners.len()].copy_from_slice(rewards);
+    season.budget = budget;
+    season.claim_deadline = claim_deadline;
+
+    emit!(SeasonEnded {
+        season_id,
+        winners,
+        scores,
+        rewards: rewards.to_vec(),
+        budget,
+        claim_deadline,
+    });
+    Ok(())
+}
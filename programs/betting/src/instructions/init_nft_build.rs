@@ -4,7 +4,6 @@ use anchor_lang::{
 };
 
 use crate::{constants::*, error::*, instructions::*, states::*, utils::*};
-use std::mem::size_of;
 
 #[warn(unused_doc_comments)]
 #[derive(Accounts)]
@@ -17,7 +16,7 @@ pub struct InitNftBuild<'info> {
       seeds = [NFT_BUILD_STATE_SEED, user.key().as_ref()],
       bump,
       payer = user,
-      space = 8 + size_of::<NftBuildState>()
+      space = 8 + NftBuildState::LEN
   )]
   pub nft_build_state: Box<Account<'info, NftBuildState>>,
   
@@ -35,6 +34,7 @@ impl<'info> InitNftBuild<'info> {
 pub fn handler<'a, 'b, 'c, 'info>(
   ctx: Context<'a, 'b, 'c, 'info, InitNftBuild<'info>>,
 ) -> Result<()> {
+  ctx.accounts.nft_build_state.version = NftBuildState::VERSION;
   ctx.accounts.nft_build_state.user = ctx.accounts.user.key();
   Ok(())
 }
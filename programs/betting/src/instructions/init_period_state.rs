@@ -0,0 +1,52 @@
+use crate::{constants::*, error::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(kind: u8, user_key: Pubkey)]
+pub struct InitPeriodState<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    #[account(
+        seeds = [PERIOD_CONFIG_SEED],
+        bump,
+        constraint = is_current_layout(&period_config) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub period_config: Box<Account<'info, PeriodConfig>>,
+
+    #[account(
+        init,
+        seeds = [
+            PERIOD_STATE_SEED,
+            &[kind],
+            user_key.as_ref(),
+            &period_config.kind(kind)?.current_period()?.to_le_bytes()
+        ],
+        bump,
+        payer = payer,
+        space = 8 + PeriodState::LEN
+    )]
+    pub user_period_state: Box<Account<'info, PeriodState>>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> InitPeriodState<'info> {
+    pub fn validate(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// Create the user's state for the current period of a kind, user_bet also creates it on demand.
+#[access_control(ctx.accounts.validate())]
+pub fn handler(ctx: Context<InitPeriodState>, kind: u8, user_key: Pubkey) -> Result<()> {
+    let accts = ctx.accounts;
+    let period_kind = accts.period_config.kind(kind)?;
+    let period = period_kind.current_period()?;
+    accts.user_period_state.version = PeriodState::VERSION;
+    accts.user_period_state.kind = kind;
+    accts.user_period_state.period = period;
+    accts.user_period_state.user = user_key;
+    accts.user_period_state.start_time = period_kind.start_time(period);
+    Ok(())
+}
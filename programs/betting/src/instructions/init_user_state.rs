@@ -1,8 +1,6 @@
 use crate::{constants::*, states::*};
 use anchor_lang::prelude::*;
 
-use std::mem::size_of;
-
 #[derive(Accounts)]
 #[instruction(user_key: Pubkey)]
 pub struct InitUserState<'info> {
@@ -14,7 +12,7 @@ pub struct InitUserState<'info> {
         seeds = [USER_STATE_SEED, user_key.as_ref()],
         bump,
         payer = payer,
-        space = 8 + size_of::<UserState>()
+        space = 8 + UserState::LEN
     )]
     pub user_state: Box<Account<'info, UserState>>,
     pub system_program: Program<'info, System>,
@@ -30,6 +28,7 @@ impl<'info> InitUserState<'info> {
 #[access_control(ctx.accounts.validate())]
 pub fn handler(ctx: Context<InitUserState>, user_key: Pubkey) -> Result<()> {
     let accts = ctx.accounts;
+    accts.user_state.version = UserState::VERSION;
     accts.user_state.user = user_key;
     Ok(())
 }
@@ -1,12 +1,10 @@
-use crate::{constants::*, error::*, states::*};
+use crate::{constants::*, error::*, states::*, utils::*};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{self, Mint, Token, TokenAccount},
 };
 
-use std::mem::size_of;
-
 #[derive(Accounts)]
 pub struct Initialize<'info> {
     #[account(mut)]
@@ -17,7 +15,7 @@ pub struct Initialize<'info> {
         seeds = [GLOBAL_STATE_SEED],
         bump,
         payer = authority,
-        space = 8 + size_of::<GlobalState>()
+        space = 8 + GlobalState::LEN
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
@@ -31,18 +29,28 @@ pub struct Initialize<'info> {
 
     #[account(
         init,
-        associated_token::mint = rank_mint,
-        associated_token::authority = global_state,
-        payer = authority
+        seeds = [BET_MINT_SEED, token_mint.key().as_ref()],
+        bump,
+        payer = authority,
+        space = 8 + BetMint::LEN
     )]
-    pub feel_vault_ata: Account<'info, TokenAccount>,
+    pub bet_mint: Box<Account<'info, BetMint>>,
+
+    /// CHECK: global_state's ATA of rank_mint, created in handler
+    #[account(mut)]
+    pub feel_vault_ata: AccountInfo<'info>,
 
     pub token_mint: Account<'info, Mint>,
-    pub rank_mint: Account<'info, Mint>,
+    /// CHECK: FEEL mint, owned by feel_token_program
+    #[account(owner = feel_token_program.key() @ BettingError::IncorrectMint)]
+    pub rank_mint: AccountInfo<'info>,
 
     /// CHECK: no need to check
     pub treasury: AccountInfo<'info>,
     pub token_program: Program<'info, Token>,
+    /// CHECK: token program or Token-2022 of the FEEL mint
+    #[account(constraint = is_token_program(feel_token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub feel_token_program: AccountInfo<'info>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
@@ -73,19 +81,43 @@ pub fn handler(ctx: Context<Initialize>,
   ada_pyth_account: Pubkey,
 ) -> Result<()> {
     let accts = ctx.accounts;
+    accts.global_state.version = GlobalState::VERSION;
     accts.global_state.is_initialized = 1;
     accts.global_state.authority = new_authority;
     accts.global_state.treasury = accts.treasury.key();
     accts.global_state.platform_fee_rate = INITIAL_PLATFORM_FEE_RATE;
     accts.global_state.referral_fee_rate = INITIAL_REF_FEE_RATE;
+    accts.global_state.refund_grace_period = INITIAL_REFUND_GRACE_PERIOD;
     accts.global_state.token_mint = accts.token_mint.key();
     accts.global_state.rank_mint = accts.rank_mint.key();
+    create_ata_if_needed(
+        accts.authority.to_account_info(),
+        accts.feel_vault_ata.to_account_info(),
+        accts.global_state.to_account_info(),
+        accts.rank_mint.to_account_info(),
+        accts.system_program.to_account_info(),
+        accts.feel_token_program.to_account_info(),
+        accts.rent.to_account_info(),
+    )?;
+
+    accts.bet_mint.version = BetMint::VERSION;
+    accts.bet_mint.mint = accts.token_mint.key();
+    accts.bet_mint.is_enabled = 1;
     
     accts.global_state.btc_pyth_account = btc_pyth_account;
     accts.global_state.eth_pyth_account = eth_pyth_account;
     accts.global_state.sol_pyth_account = sol_pyth_account;
     accts.global_state.avax_pyth_account = avax_pyth_account;
     accts.global_state.ada_pyth_account = ada_pyth_account;
+
+    // every role starts with the new authority until set_roles splits them
+    accts.global_state.set_roles(&Roles {
+        operator: new_authority,
+        ranker: new_authority,
+        minter: new_authority,
+        treasurer: new_authority,
+        pauser: new_authority,
+    });
     
     Ok(())
 }
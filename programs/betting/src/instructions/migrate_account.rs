@@ -0,0 +1,133 @@
+use anchor_lang::{
+    prelude::*,
+    system_program::{self, Transfer},
+    Discriminator,
+};
+
+use crate::{constants::*, error::*, states::{legacy::*, *}, utils::*};
+
+use std::mem::size_of;
+
+#[derive(Accounts)]
+pub struct MigrateAccount<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+
+    /// CHECK: discriminator and layout are checked in handler
+    #[account(mut, owner = crate::ID)]
+    pub target_account: AccountInfo<'info>,
+
+    /// CHECK: fee rates to book arenas settled before the upgrade, read in handler,
+    /// it is the target itself when migrating the global state
+    #[account(seeds = [GLOBAL_STATE_SEED], bump)]
+    pub global_state: AccountInfo<'info>,
+
+    pub system_program: Program<'info, System>,
+}
+
+impl<'info> MigrateAccount<'info> {
+    fn validate(&self) -> Result<()> {
+        require!(
+            self.target_account.data_len() >= 8,
+            BettingError::UnsupportedAccountVersion
+        );
+        Ok(())
+    }
+    fn pay_rent_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
+        CpiContext::new(
+            self.system_program.to_account_info(),
+            Transfer {
+                from: self.payer.to_account_info(),
+                to: self.target_account.to_account_info(),
+            },
+        )
+    }
+}
+
+fn is_current<T: Versioned>(data: &[u8]) -> bool {
+    data.len() == 8 + T::LEN && data.get(8) == Some(&T::VERSION)
+}
+
+/// decode an account written before the version byte and upgrade it to `New`
+fn upgrade_unversioned<Old, New>(data: &[u8]) -> Result<New>
+where
+    Old: AnchorDeserialize,
+    New: From<Old>,
+{
+    // accounts without version byte were sized with size_of
+    require!(
+        data.len() == 8 + size_of::<Old>(),
+        BettingError::UnsupportedAccountVersion
+    );
+    let old = Old::deserialize(&mut &data[8..])?;
+    Ok(New::from(old))
+}
+
+/// Reallocate a state account to the current layout and rewrite it in place.
+/// Anyone can migrate, the payer covers any extra rent.
+#[access_control(ctx.accounts.validate())]
+pub fn handler(ctx: Context<MigrateAccount>) -> Result<()> {
+    let accts = ctx.accounts;
+
+    let mut new_data: Vec<u8> = Vec::new();
+    {
+        let data = accts.target_account.try_borrow_data()?;
+        let discriminator = &data[..8];
+        if discriminator == GlobalState::discriminator() {
+            require!(!is_current::<GlobalState>(&data), BettingError::AlreadyMigrated);
+            upgrade_unversioned::<GlobalStateV0, GlobalState>(&data)?
+                .try_serialize(&mut new_data)?;
+        } else if discriminator == ArenaState::discriminator() {
+            require!(!is_current::<ArenaState>(&data), BettingError::AlreadyMigrated);
+            let mut arena_state = upgrade_unversioned::<ArenaStateV0, ArenaState>(&data)?;
+            arena_state.stamp_missing_start(Clock::get()?.unix_timestamp as u64);
+            // winners of arenas settled before the upgrade are owed at the rates of that time,
+            // the global state is migrated first, before any config update
+            if arena_state.status == ArenaStatus::EndSuccess as u8 {
+                let global_state = Account::<GlobalState>::try_from(&accts.global_state)?;
+                require!(
+                    is_current_layout(&global_state),
+                    BettingError::UnsupportedAccountVersion
+                );
+                arena_state.book_winnings(
+                    global_state.platform_fee_rate,
+                    global_state.referral_fee_rate,
+                );
+            }
+            arena_state.try_serialize(&mut new_data)?;
+        } else if discriminator == UserState::discriminator() {
+            require!(!is_current::<UserState>(&data), BettingError::AlreadyMigrated);
+            upgrade_unversioned::<UserStateV0, UserState>(&data)?.try_serialize(&mut new_data)?;
+        } else if discriminator == UserBetState::discriminator() {
+            require!(!is_current::<UserBetState>(&data), BettingError::AlreadyMigrated);
+            upgrade_unversioned::<UserBetStateV0, UserBetState>(&data)?
+                .try_serialize(&mut new_data)?;
+        } else if discriminator == NftBuildState::discriminator() {
+            require!(!is_current::<NftBuildState>(&data), BettingError::AlreadyMigrated);
+            upgrade_unversioned::<NftBuildStateV0, NftBuildState>(&data)?
+                .try_serialize(&mut new_data)?;
+        } else if discriminator == EightBoxState::discriminator() {
+            require!(!is_current::<EightBoxState>(&data), BettingError::AlreadyMigrated);
+            upgrade_unversioned::<EightBoxStateV0, EightBoxState>(&data)?
+                .try_serialize(&mut new_data)?;
+        } else {
+            // accounts added with versioning have no older layout,
+            // baseline hour, day and week accounts are claimed and closed instead
+            return Err(error!(BettingError::UnsupportedAccountVersion));
+        }
+    }
+
+    let new_len = new_data.len();
+    let rent = Rent::get()?.minimum_balance(new_len);
+    let lamports = accts.target_account.lamports();
+    if rent > lamports {
+        system_program::transfer(accts.pay_rent_context(), rent - lamports)?;
+    }
+    accts.target_account.realloc(new_len, false)?;
+    accts
+        .target_account
+        .try_borrow_mut_data()?
+        .copy_from_slice(&new_data);
+
+    Ok(())
+}
@@ -11,19 +11,20 @@ use mpl_token_metadata::ID as MetadataProgramId;
 #[derive(Accounts)]
 pub struct MintFragment<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub minter: Signer<'info>,
 
     #[account(
       seeds = [GLOBAL_STATE_SEED],
       bump,
-      has_one = authority
+      has_one = minter,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
     #[account(mut)]
     pub mint: Account<'info, Mint>,
 
-    #[account(init_if_needed, payer = authority, associated_token::mint = mint, associated_token::authority = authority)]
+    #[account(init_if_needed, payer = minter, associated_token::mint = mint, associated_token::authority = minter)]
     pub user_ata: Account<'info, TokenAccount>,
 
     pub token_program: Program<'info, Token>,
@@ -47,7 +48,7 @@ pub fn handler<'a, 'b, 'c, 'info>(
     let rem_accts = &mut ctx.remaining_accounts.iter();
 
     mint_fragment(
-        accts.authority.to_account_info(),
+        accts.minter.to_account_info(),
         accts.mint.to_account_info(),
         accts.user_ata.to_account_info(),
         accts.global_state.to_account_info(),
@@ -16,41 +16,53 @@ pub use end_arena::*;
 pub mod claim_reward;
 pub use claim_reward::*;
 
+pub mod settle_loss;
+pub use settle_loss::*;
+
+pub mod settle_win;
+pub use settle_win::*;
+
 pub mod init_user_state;
 pub use init_user_state::*;
 
 pub mod claim_referral_reward;
 pub use claim_referral_reward::*;
 
-pub mod init_hour_state;
-pub use init_hour_state::*;
-
-pub mod init_day_state;
-pub use init_day_state::*;
-
-pub mod init_week_state;
-pub use init_week_state::*;
+pub mod init_period_state;
+pub use init_period_state::*;
 
 pub mod init_eight_box_state;
 pub use init_eight_box_state::*;
 
-pub mod end_hour;
-pub use end_hour::*;
+pub mod end_period;
+pub use end_period::*;
+
+pub mod claim_rank_reward;
+pub use claim_rank_reward::*;
 
-pub mod end_day;
-pub use end_day::*;
+pub mod claim_legacy_rank_reward;
+pub use claim_legacy_rank_reward::*;
 
-pub mod end_week;
-pub use end_week::*;
+pub mod set_period_vesting;
+pub use set_period_vesting::*;
 
-pub mod claim_hour_rank_reward;
-pub use claim_hour_rank_reward::*;
+pub mod set_period_pool;
+pub use set_period_pool::*;
 
-pub mod claim_day_rank_reward;
-pub use claim_day_rank_reward::*;
+pub mod set_eight_box_prizes;
+pub use set_eight_box_prizes::*;
 
-pub mod claim_week_rank_reward;
-pub use claim_week_rank_reward::*;
+pub mod withdraw_vested;
+pub use withdraw_vested::*;
+
+pub mod create_season;
+pub use create_season::*;
+
+pub mod end_season;
+pub use end_season::*;
+
+pub mod claim_season_trophy;
+pub use claim_season_trophy::*;
 
 pub mod claim_eight_box;
 pub use claim_eight_box::*;
@@ -58,6 +70,9 @@ pub use claim_eight_box::*;
 pub mod return_bet;
 pub use return_bet::*;
 
+pub mod trigger_refund;
+pub use trigger_refund::*;
+
 pub mod cancel_arena;
 pub use cancel_arena::*;
 
@@ -79,6 +94,36 @@ pub use burn_fragments::*;
 pub mod init_nft_build;
 pub use init_nft_build::*;
 
+pub mod update_config;
+pub use update_config::*;
+
+pub mod propose_authority;
+pub use propose_authority::*;
+
+pub mod accept_authority;
+pub use accept_authority::*;
+
+pub mod set_roles;
+pub use set_roles::*;
+
+pub mod set_paused;
+pub use set_paused::*;
+
+pub mod set_bet_mint;
+pub use set_bet_mint::*;
+
+pub mod set_fee_split;
+pub use set_fee_split::*;
+
+pub mod set_period_kind;
+pub use set_period_kind::*;
+
+pub mod distribute_fees;
+pub use distribute_fees::*;
+
+pub mod migrate_account;
+pub use migrate_account::*;
+
 // for test
 pub mod mint_fragment;
 pub use mint_fragment::*;
@@ -1,18 +1,17 @@
-use crate::{constants::*, states::*};
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
 use anchor_lang::prelude::*;
 
-use std::mem::size_of;
-
 #[derive(Accounts)]
 #[instruction(arena_id: u64)]
 pub struct OpenArena<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub operator: Signer<'info>,
 
     #[account(
       seeds = [GLOBAL_STATE_SEED],
       bump,
-      has_one = authority
+      has_one = operator,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
@@ -20,10 +19,18 @@ pub struct OpenArena<'info> {
       init,
       seeds = [ARENA_STATE_SEED, &arena_id.to_le_bytes()],
       bump,
-      payer = authority,
-      space = 8 + size_of::<ArenaState>()
+      payer = operator,
+      space = 8 + ArenaState::LEN
     )]
     pub arena_state: Box<Account<'info, ArenaState>>,
+
+    #[account(
+      seeds = [BET_MINT_SEED, bet_mint.mint.as_ref()],
+      bump,
+      constraint = is_current_layout(&bet_mint) @ BettingError::UnsupportedAccountVersion,
+      constraint = bet_mint.is_enabled == 1 @ BettingError::BetMintDisabled
+    )]
+    pub bet_mint: Box<Account<'info, BetMint>>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
 }
@@ -39,7 +46,19 @@ pub fn handler(ctx: Context<OpenArena>, arena_id: u64) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp as u64;
 
     let accts = ctx.accounts;
+    accts.arena_state.version = ArenaState::VERSION;
     accts.arena_state.arena_id = arena_id;
     accts.arena_state.status = ArenaStatus::Opened as u8;
+    accts.arena_state.bet_mint = accts.bet_mint.mint;
+    accts.arena_state.is_booked = 1;
+    // start_arena overwrites both, they only date an arena that is never started
+    accts.arena_state.start_timestamp = current_time;
+    accts.arena_state.duration = accts.global_state.arena_duration;
+
+    emit!(ArenaOpened {
+        arena_id,
+        bet_mint: accts.bet_mint.mint,
+        timestamp: current_time,
+    });
     Ok(())
 }
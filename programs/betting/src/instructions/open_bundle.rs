@@ -3,7 +3,7 @@ use anchor_lang::{
     solana_program::{program::invoke_signed, pubkey},
 };
 
-use crate::{constants::*, error::*, instructions::*, states::*, utils::*};
+use crate::{constants::*, error::*, events::*, instructions::*, states::*, utils::*};
 use anchor_spl::{
     associated_token::AssociatedToken,
     token::{self, Burn, Mint, MintTo, Token, TokenAccount, Transfer},
@@ -27,6 +27,8 @@ pub struct OpenBundle<'info> {
         has_one = sol_pyth_account,
         has_one = avax_pyth_account,
         has_one = ada_pyth_account,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion,
+        constraint = !global_state.is_paused(PAUSE_BUNDLE_OPENING) @ BettingError::BundleOpeningPaused
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
@@ -132,20 +134,17 @@ pub fn handler<'a, 'b, 'c, 'info>(
         .position(|&name| name.to_string().eq(&bundle_name))
         .unwrap_or(0);
 
-    msg!("bundle id {} reward count {}", bundle_id, BUNDLE_REWARD_COUNT[bundle_id]);
+    let mut fragments = Vec::new();
     for i in 0..BUNDLE_REWARD_COUNT[bundle_id] {
         let pyth_account = pyth_vec[i as usize];
         let pyth_price_data = &pyth_account.try_borrow_data()?;
         let pyth_price = pyth_client::cast::<pyth_client::Price>(pyth_price_data);
-        msg!("pyth_price.agg.price = {}", pyth_price.agg.price);
 
         let rand_val = (pyth_price.agg.price as u64).checked_add(current_time).unwrap() % RATE_DEVIDER;
         let fragment_id = BUNDLE_FRAGMENT_RATE[bundle_id as usize]
             .iter()
             .position(|&rate| rand_val <= rate as u64)
             .unwrap_or(0);
-        
-        msg!("rand fragment_id = {}", fragment_id);
 
         let iter = &mut ctx.remaining_accounts.iter();
         
@@ -169,6 +168,7 @@ pub fn handler<'a, 'b, 'c, 'info>(
             ctx.program_id,
             fragment_id as u8 + 1,
         )?;
+        fragments.push(fragment_id as u8 + 1);
     }
 
     token::burn(
@@ -182,6 +182,13 @@ pub fn handler<'a, 'b, 'c, 'info>(
         ),
         1,
     )?;
+
+    emit!(BundleOpened {
+        user: accts.user.key(),
+        bundle_id: bundle_id as u8,
+        bundle_mint: accts.bundle_mint.key(),
+        fragments,
+    });
     //Err(ProgramError::InvalidAccountData.into())
     Ok(())
 }
@@ -0,0 +1,30 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct ProposeAuthority<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        has_one = authority,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+}
+
+/// First step of an authority transfer.
+/// Nothing changes until the proposed key signs accept_authority,
+/// proposing the default pubkey cancels a pending transfer.
+pub fn handler(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+    let accts = ctx.accounts;
+    accts.global_state.pending_authority = new_authority;
+
+    emit!(AuthorityProposed {
+        authority: accts.authority.key(),
+        pending_authority: new_authority,
+    });
+    Ok(())
+}
@@ -1,12 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, error::*, instructions::*, states::*, utils::*};
-use anchor_spl::{
-    associated_token::{self, AssociatedToken},
-    token::{self, Mint, Token, TokenAccount, Transfer},
-};
-
-use std::mem::size_of;
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
 
 #[derive(Accounts)]
 #[instruction(arena_id: u64)]
@@ -17,7 +11,7 @@ pub struct ReturnBet<'info> {
     #[account(
       seeds = [GLOBAL_STATE_SEED],
       bump,
-      has_one = token_mint
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
@@ -25,6 +19,7 @@ pub struct ReturnBet<'info> {
       mut,
       seeds = [ARENA_STATE_SEED, &arena_id.to_le_bytes()],
       bump,
+      constraint = is_current_layout(&arena_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub arena_state: Account<'info, ArenaState>,
 
@@ -32,25 +27,44 @@ pub struct ReturnBet<'info> {
       mut,
       seeds = [USER_BET_SEED, user.key().as_ref(), &arena_id.to_le_bytes()],
       bump,
+      constraint = is_current_layout(&user_bet_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub user_bet_state: Account<'info, UserBetState>,
 
+    /// CHECK: user's ATA of token_mint, the user wallet in SOL arenas. checked in pay_out
+    #[account(mut)]
+    pub user_ata: AccountInfo<'info>,
+
+    /// CHECK: [UNWRAP_SEED, user] PDA, only used in SOL arenas. checked in pay_out
+    #[account(mut)]
+    pub unwrap_account: AccountInfo<'info>,
+
+    /// CHECK: global_state's ATA of token_mint under token_program
     #[account(
       mut,
-      associated_token::mint = token_mint,
-      associated_token::authority = user
+      address = associated_token_address(&global_state.key(), &token_mint.key(), token_program.key) @ BettingError::IncorrectTokenAccount
     )]
-    pub user_ata: Account<'info, TokenAccount>,
+    pub escrow_ata: AccountInfo<'info>,
 
     #[account(
       mut,
-      associated_token::mint = token_mint,
-      associated_token::authority = global_state,
+      seeds = [BET_MINT_SEED, token_mint.key().as_ref()],
+      bump,
+      constraint = is_current_layout(&bet_mint) @ BettingError::UnsupportedAccountVersion
     )]
-    pub escrow_ata: Account<'info, TokenAccount>,
+    pub bet_mint: Box<Account<'info, BetMint>>,
 
-    pub token_mint: Account<'info, Mint>,
-    pub token_program: Program<'info, Token>,
+    /// CHECK: mint of the arena, owned by token_program
+    #[account(
+      address = arena_state.mint_or(global_state.token_mint) @ BettingError::IncorrectMint,
+      owner = token_program.key() @ BettingError::IncorrectMint
+    )]
+    pub token_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022
+    #[account(constraint = is_token_program(token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub token_program: AccountInfo<'info>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
 }
 
 impl<'info> ReturnBet<'info> {
@@ -59,7 +73,8 @@ impl<'info> ReturnBet<'info> {
         // require!(current_time > )
 
         require!(
-            self.arena_state.status == ArenaStatus::Cancelled as u8,
+            self.arena_state.status == ArenaStatus::Cancelled as u8
+                || self.arena_state.status == ArenaStatus::Refunding as u8,
             BettingError::ArenaNotCancelled
         );
         // check if user has claimed
@@ -69,14 +84,20 @@ impl<'info> ReturnBet<'info> {
         );
         Ok(())
     }
-    fn return_bet_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
-        CpiContext::new(
+    fn return_bet(&self, signer_seeds: &[&[u8]], amount: u64) -> Result<u64> {
+        pay_out(
+            self.escrow_ata.to_account_info(),
+            self.global_state.to_account_info(),
+            signer_seeds,
+            self.user.to_account_info(),
+            self.user_ata.to_account_info(),
+            self.unwrap_account.to_account_info(),
+            self.token_mint.to_account_info(),
             self.token_program.to_account_info(),
-            Transfer {
-                from: self.escrow_ata.to_account_info(),
-                to: self.user_ata.to_account_info(),
-                authority: self.global_state.to_account_info(),
-            },
+            self.system_program.to_account_info(),
+            self.rent.to_account_info(),
+            &crate::ID,
+            amount,
         )
     }
 }
@@ -90,12 +111,22 @@ pub fn handler(ctx: Context<ReturnBet>, arena_id: u64) -> Result<()> {
         GLOBAL_STATE_SEED,
         &[*(ctx.bumps.get("global_state").unwrap())],
     ];
-    token::transfer(
-        accts.return_bet_context().with_signer(&[signer_seeds]),
-        accts.user_bet_state.bet_amount,
-    )?;
+    let received = accts.return_bet(signer_seeds, accts.user_bet_state.bet_amount)?;
 
     accts.user_bet_state.is_claimed = 1;
+    if accts.user_bet_state.is_booked == 1 {
+        accts.bet_mint.open_stakes = accts
+            .bet_mint
+            .open_stakes
+            .checked_sub(accts.user_bet_state.bet_amount)
+            .ok_or(BettingError::LiabilityUnderflow)?;
+    }
+    assert_solvent(&accts.escrow_ata, accts.bet_mint.liabilities())?;
 
+    emit!(BetReturned {
+        arena_id,
+        user: accts.user.key(),
+        amount: received,
+    });
     Ok(())
 }
@@ -0,0 +1,105 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+use anchor_spl::{associated_token::AssociatedToken, token::Mint};
+
+#[derive(Accounts)]
+pub struct SetBetMint<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        has_one = authority,
+        has_one = token_mint,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [BET_MINT_SEED, mint.key().as_ref()],
+        bump,
+        payer = authority,
+        space = 8 + BetMint::LEN
+    )]
+    pub bet_mint: Box<Account<'info, BetMint>>,
+
+    /// CHECK: global_state's ATA of mint, created in handler if missing
+    #[account(mut)]
+    pub escrow_ata: AccountInfo<'info>,
+
+    #[account(
+        seeds = [FEE_SPLIT_SEED],
+        bump,
+        constraint = is_current_layout(&fee_split) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub fee_split: Box<Account<'info, FeeSplit>>,
+
+    /// CHECK: fee_split's ATA of mint, created in handler if missing
+    #[account(mut)]
+    pub fee_vault_ata: AccountInfo<'info>,
+
+    /// CHECK: token program or Token-2022 mint, owned by token_program
+    #[account(owner = token_program.key() @ BettingError::IncorrectMint)]
+    pub mint: AccountInfo<'info>,
+    pub token_mint: Box<Account<'info, Mint>>,
+    /// CHECK: token program or Token-2022
+    #[account(constraint = is_token_program(token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub token_program: AccountInfo<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> SetBetMint<'info> {
+    fn validate(&self, is_enabled: u8) -> Result<()> {
+        require!(is_enabled <= 1, BettingError::InvalidParameter);
+        require!(
+            self.bet_mint.version == 0 || self.bet_mint.version == BetMint::VERSION,
+            BettingError::UnsupportedAccountVersion
+        );
+        // period volumes add up amounts of every mint, SOL bets are left out of them
+        require!(
+            mint_decimals(&self.mint)? == self.token_mint.decimals || is_native_mint(self.mint.key),
+            BettingError::IncorrectMint
+        );
+        Ok(())
+    }
+}
+
+/// Add a mint to the bet allow-list, or enable and disable it.
+/// Creates the escrow and fee vault of the mint on first use.
+#[access_control(ctx.accounts.validate(is_enabled))]
+pub fn handler(ctx: Context<SetBetMint>, is_enabled: u8) -> Result<()> {
+    let accts = ctx.accounts;
+    create_ata_if_needed(
+        accts.authority.to_account_info(),
+        accts.escrow_ata.to_account_info(),
+        accts.global_state.to_account_info(),
+        accts.mint.to_account_info(),
+        accts.system_program.to_account_info(),
+        accts.token_program.to_account_info(),
+        accts.rent.to_account_info(),
+    )?;
+    create_ata_if_needed(
+        accts.authority.to_account_info(),
+        accts.fee_vault_ata.to_account_info(),
+        accts.fee_split.to_account_info(),
+        accts.mint.to_account_info(),
+        accts.system_program.to_account_info(),
+        accts.token_program.to_account_info(),
+        accts.rent.to_account_info(),
+    )?;
+    if accts.bet_mint.version == 0 {
+        accts.bet_mint.version = BetMint::VERSION;
+        accts.bet_mint.mint = accts.mint.key();
+    }
+    accts.bet_mint.is_enabled = is_enabled;
+
+    emit!(BetMintUpdated {
+        mint: accts.mint.key(),
+        is_enabled,
+    });
+    Ok(())
+}
@@ -0,0 +1,99 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetEightBoxPrizes<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        has_one = authority,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [EIGHT_BOX_CONFIG_SEED],
+        bump,
+        payer = authority,
+        space = 8 + EightBoxConfig::LEN
+    )]
+    pub eight_box_config: Box<Account<'info, EightBoxConfig>>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> SetEightBoxPrizes<'info> {
+    fn validate(&self, prizes: &[EightBoxPrize], claim_window: u64) -> Result<()> {
+        let config = &self.eight_box_config;
+        require!(
+            config.version == 0 || config.version == EightBoxConfig::VERSION,
+            BettingError::UnsupportedAccountVersion
+        );
+        require!(
+            !prizes.is_empty() && prizes.len() <= MAX_EIGHT_BOX_PRIZES,
+            BettingError::InvalidParameter
+        );
+        require!(
+            (MIN_CLAIM_WINDOW..=MAX_CLAIM_WINDOW).contains(&claim_window),
+            BettingError::InvalidParameter
+        );
+        // claims of running boxes are bits keyed by prize index
+        let current = config.prizes();
+        require!(prizes.len() >= current.len(), BettingError::EightBoxPrizeLocked);
+        for (prize, current) in prizes.iter().zip(current) {
+            require!(
+                prize.kind == current.kind && prize.id == current.id,
+                BettingError::EightBoxPrizeLocked
+            );
+        }
+        let mut volume = 0;
+        for prize in prizes {
+            require!(prize.volume > volume, BettingError::InvalidParameter);
+            volume = prize.volume;
+            let is_valid = match prize.kind {
+                kind if kind == EightBoxPrizeKind::Bundle as u8 => {
+                    (prize.id as usize) < BUNDLE_NAMES.len()
+                }
+                kind if kind == EightBoxPrizeKind::Feel as u8 => prize.amount > 0,
+                kind if kind == EightBoxPrizeKind::Fragment as u8 => {
+                    prize.id >= 1
+                        && prize.id as usize <= FRAGMENT_NAMES.len()
+                        && prize.amount > 0
+                        && prize.amount <= MAX_EIGHT_BOX_FRAGMENTS
+                }
+                _ => false,
+            };
+            require!(is_valid, BettingError::InvalidParameter);
+        }
+        Ok(())
+    }
+}
+
+/// Update the eight-hour box prizes, ordered by increasing volume, and their claim window.
+/// Prizes are matched to claims by index, so existing prizes keep their kind and id
+/// and new ones are appended, volumes and amounts of existing prizes may be retuned.
+/// Changes also apply to the running boxes, as does the claim window.
+#[access_control(ctx.accounts.validate(&prizes, claim_window))]
+pub fn handler(
+    ctx: Context<SetEightBoxPrizes>,
+    prizes: Vec<EightBoxPrize>,
+    claim_window: u64,
+) -> Result<()> {
+    let config = &mut ctx.accounts.eight_box_config;
+    config.version = EightBoxConfig::VERSION;
+    config.prize_count = prizes.len() as u8;
+    config.prizes = [EightBoxPrize::default(); MAX_EIGHT_BOX_PRIZES];
+    config.prizes[..prizes.len()].copy_from_slice(&prizes);
+    config.claim_window = claim_window;
+
+    emit!(EightBoxPrizesUpdated {
+        prizes,
+        claim_window,
+    });
+    Ok(())
+}
@@ -0,0 +1,87 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token::{Mint, Token, TokenAccount},
+};
+
+#[derive(Accounts)]
+pub struct SetFeeSplit<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        has_one = authority,
+        has_one = token_mint,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [FEE_SPLIT_SEED],
+        bump,
+        payer = authority,
+        space = 8 + FeeSplit::LEN
+    )]
+    pub fee_split: Box<Account<'info, FeeSplit>>,
+
+    #[account(
+        init_if_needed,
+        associated_token::mint = token_mint,
+        associated_token::authority = fee_split,
+        payer = authority
+    )]
+    pub fee_vault_ata: Box<Account<'info, TokenAccount>>,
+
+    pub token_mint: Box<Account<'info, Mint>>,
+    pub token_program: Program<'info, Token>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> SetFeeSplit<'info> {
+    fn validate(&self, recipients: &[Pubkey], shares: &[u64]) -> Result<()> {
+        require!(
+            self.fee_split.version == 0 || self.fee_split.version == FeeSplit::VERSION,
+            BettingError::UnsupportedAccountVersion
+        );
+        require!(
+            !recipients.is_empty()
+                && recipients.len() <= MAX_FEE_RECIPIENTS
+                && recipients.len() == shares.len(),
+            BettingError::InvalidParameter
+        );
+        require!(
+            recipients.iter().all(|r| *r != Pubkey::default()),
+            BettingError::InvalidParameter
+        );
+        let total = shares
+            .iter()
+            .try_fold(0u64, |total, share| total.checked_add(*share));
+        require!(
+            total == Some(FEE_RATE_DENOMINATOR),
+            BettingError::ConfigOutOfBounds
+        );
+        Ok(())
+    }
+}
+
+/// Create or replace the fee recipients and their shares.
+/// Fees collected by end_arena wait in fee_vault_ata until distribute_fees.
+#[access_control(ctx.accounts.validate(&recipients, &shares))]
+pub fn handler(ctx: Context<SetFeeSplit>, recipients: Vec<Pubkey>, shares: Vec<u64>) -> Result<()> {
+    let fee_split = &mut ctx.accounts.fee_split;
+    fee_split.version = FeeSplit::VERSION;
+    fee_split.recipient_count = recipients.len() as u8;
+    fee_split.recipients = [Pubkey::default(); MAX_FEE_RECIPIENTS];
+    fee_split.shares = [0; MAX_FEE_RECIPIENTS];
+    fee_split.recipients[..recipients.len()].copy_from_slice(&recipients);
+    fee_split.shares[..shares.len()].copy_from_slice(&shares);
+
+    emit!(FeeSplitUpdated { recipients, shares });
+    Ok(())
+}
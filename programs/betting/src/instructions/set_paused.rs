@@ -0,0 +1,31 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    pub pauser: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        has_one = pauser,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+}
+
+/// Replace the pause bits, PAUSE_ALL halts every feature at once and 0 resumes all.
+pub fn handler(ctx: Context<SetPaused>, paused: u8) -> Result<()> {
+    require!(paused & !PAUSE_ALL == 0, BettingError::InvalidParameter);
+
+    let accts = ctx.accounts;
+    let old_paused = accts.global_state.paused;
+    accts.global_state.paused = paused;
+
+    emit!(PauseUpdated {
+        old_paused,
+        new_paused: paused,
+    });
+    Ok(())
+}
@@ -0,0 +1,137 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPeriodKind<'info> {
+    #[account(mut)]
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        has_one = authority,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        init_if_needed,
+        seeds = [PERIOD_CONFIG_SEED],
+        bump,
+        payer = authority,
+        space = 8 + PeriodConfig::LEN
+    )]
+    pub period_config: Box<Account<'info, PeriodConfig>>,
+
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> SetPeriodKind<'info> {
+    fn validate(
+        &self,
+        kind: u8,
+        length: u64,
+        epoch_offset: u64,
+        is_enabled: u8,
+        ranking: u8,
+        claim_window: u64,
+        tiers: &[PeriodTier],
+    ) -> Result<()> {
+        let config = &self.period_config;
+        require!(
+            config.version == 0 || config.version == PeriodConfig::VERSION,
+            BettingError::UnsupportedAccountVersion
+        );
+        // kinds are appended in order and never removed, accounts refer to them by id
+        require!(
+            kind <= config.kind_count && (kind as usize) < MAX_PERIOD_KINDS,
+            BettingError::UnknownPeriodKind
+        );
+        require!(
+            length >= MIN_PERIOD_LENGTH
+                && epoch_offset < length
+                && is_enabled <= 1
+                && ranking <= Ranking::Profit as u8
+                && (MIN_CLAIM_WINDOW..=MAX_CLAIM_WINDOW).contains(&claim_window),
+            BettingError::InvalidPeriodConfig
+        );
+        // moving period boundaries would orphan the states of the running periods,
+        // and their results are published for the ranking they were played under
+        if kind < config.kind_count {
+            let current = &config.kinds[kind as usize];
+            require!(
+                current.length == length
+                    && current.epoch_offset == epoch_offset
+                    && current.ranking == ranking,
+                BettingError::InvalidPeriodConfig
+            );
+        }
+        require!(
+            !tiers.is_empty() && tiers.len() <= MAX_PERIOD_TIERS,
+            BettingError::InvalidPeriodConfig
+        );
+        let mut last_rank = 0;
+        for tier in tiers {
+            require!(
+                tier.last_rank > last_rank && tier.prize <= RankPrize::Nft as u8,
+                BettingError::InvalidPeriodConfig
+            );
+            require!(
+                (tier.bundle_id as usize) < BUNDLE_NAMES.len(),
+                BettingError::InvalidBundleId
+            );
+            last_rank = tier.last_rank;
+        }
+        require!(
+            last_rank <= MAX_LEADERBOARD_SIZE,
+            BettingError::InvalidPeriodConfig
+        );
+        let pool_shares: u64 = tiers.iter().map(|tier| tier.pool_share as u64).sum();
+        require!(
+            pool_shares <= FEE_RATE_DENOMINATOR,
+            BettingError::InvalidPeriodConfig
+        );
+        Ok(())
+    }
+}
+
+/// Define a new period kind or update the tiers, prizes and claim window of an existing one.
+/// Tier and claim window changes apply to results published afterwards.
+#[access_control(ctx.accounts.validate(kind, length, epoch_offset, is_enabled, ranking, claim_window, &tiers))]
+pub fn handler(
+    ctx: Context<SetPeriodKind>,
+    kind: u8,
+    length: u64,
+    epoch_offset: u64,
+    is_enabled: u8,
+    ranking: u8,
+    claim_window: u64,
+    tiers: Vec<PeriodTier>,
+) -> Result<()> {
+    let config = &mut ctx.accounts.period_config;
+    config.version = PeriodConfig::VERSION;
+    if kind == config.kind_count {
+        config.kind_count += 1;
+    }
+    let period_kind = &mut config.kinds[kind as usize];
+    period_kind.length = length;
+    period_kind.epoch_offset = epoch_offset;
+    period_kind.is_enabled = is_enabled;
+    period_kind.ranking = ranking;
+    period_kind.claim_window = claim_window;
+    period_kind.tier_count = tiers.len() as u8;
+    period_kind.tiers = [PeriodTier::default(); MAX_PERIOD_TIERS];
+    period_kind.tiers[..tiers.len()].copy_from_slice(&tiers);
+
+    emit!(PeriodKindUpdated {
+        kind,
+        length,
+        epoch_offset,
+        is_enabled,
+        ranking,
+        claim_window,
+        tiers,
+    });
+    Ok(())
+}
@@ -0,0 +1,51 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPeriodPool<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        has_one = authority,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        mut,
+        seeds = [PERIOD_CONFIG_SEED],
+        bump,
+        constraint = is_current_layout(&period_config) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub period_config: Box<Account<'info, PeriodConfig>>,
+}
+
+impl<'info> SetPeriodPool<'info> {
+    fn validate(&self, kind: u8, fee_rate: u16) -> Result<()> {
+        self.period_config.kind(kind)?;
+        // every pool is paid from the same arena fee
+        let other_rates: u64 = self.period_config.kinds[..self.period_config.kind_count as usize]
+            .iter()
+            .enumerate()
+            .filter(|(id, _)| *id != kind as usize)
+            .map(|(_, period_kind)| period_kind.pool_fee_rate as u64)
+            .sum();
+        require!(
+            other_rates + fee_rate as u64 <= FEE_RATE_DENOMINATOR,
+            BettingError::InvalidPeriodConfig
+        );
+        Ok(())
+    }
+}
+
+/// Set the share of each arena fee paid into the prize pool of a kind's current period.
+/// The pool is split between the tiers by their pool shares when the result is published.
+#[access_control(ctx.accounts.validate(kind, fee_rate))]
+pub fn handler(ctx: Context<SetPeriodPool>, kind: u8, fee_rate: u16) -> Result<()> {
+    ctx.accounts.period_config.kinds[kind as usize].pool_fee_rate = fee_rate;
+
+    emit!(PeriodPoolUpdated { kind, fee_rate });
+    Ok(())
+}
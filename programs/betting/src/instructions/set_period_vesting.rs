@@ -0,0 +1,50 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetPeriodVesting<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        has_one = authority,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+        mut,
+        seeds = [PERIOD_CONFIG_SEED],
+        bump,
+        constraint = is_current_layout(&period_config) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub period_config: Box<Account<'info, PeriodConfig>>,
+}
+
+impl<'info> SetPeriodVesting<'info> {
+    fn validate(&self, kind: u8, duration: u64, cliff: u64) -> Result<()> {
+        self.period_config.kind(kind)?;
+        require!(
+            duration <= MAX_VESTING_DURATION && cliff <= duration,
+            BettingError::InvalidPeriodConfig
+        );
+        Ok(())
+    }
+}
+
+/// Set how the FEEL rank rewards of a kind vest, a zero duration pays them at once.
+/// Applies to results published afterwards.
+#[access_control(ctx.accounts.validate(kind, duration, cliff))]
+pub fn handler(ctx: Context<SetPeriodVesting>, kind: u8, duration: u64, cliff: u64) -> Result<()> {
+    let period_kind = &mut ctx.accounts.period_config.kinds[kind as usize];
+    period_kind.vesting_duration = duration;
+    period_kind.vesting_cliff = cliff;
+
+    emit!(PeriodVestingUpdated {
+        kind,
+        duration,
+        cliff,
+    });
+    Ok(())
+}
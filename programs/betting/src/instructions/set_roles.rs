@@ -0,0 +1,30 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct SetRoles<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        has_one = authority,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+}
+
+/// Assign the operator, ranker, minter, treasurer and pauser keys.
+/// All roles are replaced at once.
+pub fn handler(ctx: Context<SetRoles>, roles: Roles) -> Result<()> {
+    let accts = ctx.accounts;
+    let old_roles = accts.global_state.roles();
+    accts.global_state.set_roles(&roles);
+
+    emit!(RolesUpdated {
+        old_roles,
+        new_roles: roles,
+    });
+    Ok(())
+}
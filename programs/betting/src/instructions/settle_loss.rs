@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+
+#[derive(Accounts)]
+#[instruction(arena_id: u64)]
+pub struct SettleLoss<'info> {
+    pub settler: Signer<'info>,
+
+    #[account(
+      seeds = [GLOBAL_STATE_SEED],
+      bump,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion,
+      constraint = !global_state.is_paused(PAUSE_SETTLEMENT) @ BettingError::SettlementPaused
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+      seeds = [ARENA_STATE_SEED, &arena_id.to_le_bytes()],
+      bump,
+      constraint = is_current_layout(&arena_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub arena_state: Box<Account<'info, ArenaState>>,
+
+    /// CHECK: bettor, receives the rent of the closed bet state
+    #[account(mut)]
+    pub user: AccountInfo<'info>,
+
+    #[account(
+      mut,
+      seeds = [USER_BET_SEED, user.key().as_ref(), &arena_id.to_le_bytes()],
+      bump,
+      close = user,
+      constraint = is_current_layout(&user_bet_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub user_bet_state: Box<Account<'info, UserBetState>>,
+
+    #[account(
+      seeds = [PERIOD_CONFIG_SEED],
+      bump,
+      constraint = is_current_layout(&period_config) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub period_config: Box<Account<'info, PeriodConfig>>,
+}
+
+impl<'info> SettleLoss<'info> {
+    fn validate(&self) -> Result<()> {
+        require!(
+            self.arena_state.status == ArenaStatus::EndSuccess as u8
+                || self.arena_state.status == ArenaStatus::EndRatioBelow as u8,
+            BettingError::ArenaNotFinished
+        );
+        require!(
+            self.user_bet_state.is_up != self.arena_state.bet_result,
+            BettingError::BetResultMisMatch
+        );
+        Ok(())
+    }
+}
+
+/// Book a lost bet against the bettor's PnL and close its bet state, anyone can settle.
+/// remaining accounts: the user's period state of every profit ranked kind for the bet time
+#[access_control(ctx.accounts.validate())]
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, SettleLoss<'info>>,
+    arena_id: u64,
+) -> Result<()> {
+    let accts = ctx.accounts;
+    let loss = accts.user_bet_state.bet_amount;
+    record_pnl(
+        &accts.period_config,
+        &mut ctx.remaining_accounts.iter(),
+        ctx.program_id,
+        accts.user.key,
+        accts.user_bet_state.bet_timestamp,
+        -(loss as i64),
+    )?;
+
+    emit!(LossSettled {
+        arena_id,
+        user: accts.user.key(),
+        amount: loss,
+    });
+    Ok(())
+}
@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+
+#[derive(Accounts)]
+#[instruction(arena_id: u64)]
+pub struct SettleWin<'info> {
+    pub settler: Signer<'info>,
+
+    #[account(
+      seeds = [GLOBAL_STATE_SEED],
+      bump,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion,
+      constraint = !global_state.is_paused(PAUSE_SETTLEMENT) @ BettingError::SettlementPaused
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+      mut,
+      seeds = [ARENA_STATE_SEED, &arena_id.to_le_bytes()],
+      bump,
+      constraint = is_current_layout(&arena_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub arena_state: Box<Account<'info, ArenaState>>,
+
+    /// CHECK: bettor, only used to derive the bet state
+    pub user: AccountInfo<'info>,
+
+    #[account(
+      mut,
+      seeds = [USER_BET_SEED, user.key().as_ref(), &arena_id.to_le_bytes()],
+      bump,
+      constraint = is_current_layout(&user_bet_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub user_bet_state: Box<Account<'info, UserBetState>>,
+
+    #[account(
+      seeds = [PERIOD_CONFIG_SEED],
+      bump,
+      constraint = is_current_layout(&period_config) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub period_config: Box<Account<'info, PeriodConfig>>,
+}
+
+impl<'info> SettleWin<'info> {
+    fn validate(&self) -> Result<()> {
+        // winners of arenas under the ratio get their stake back, nothing to book
+        require!(
+            self.arena_state.status == ArenaStatus::EndSuccess as u8,
+            BettingError::NothingToSettle
+        );
+        require!(
+            self.user_bet_state.is_up == self.arena_state.bet_result,
+            BettingError::BetResultMisMatch
+        );
+        require!(
+            self.user_bet_state.is_claimed == 0 && self.user_bet_state.is_settled == 0,
+            BettingError::AlreadySettled
+        );
+        Ok(())
+    }
+}
+
+/// Book a won bet's share of the arena and its profit against the bettor's PnL, anyone can settle.
+/// The bet state stays open, claim_reward pays the booked share later.
+/// remaining accounts: the user's period state of every profit ranked kind for the bet time
+#[access_control(ctx.accounts.validate())]
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, SettleWin<'info>>,
+    arena_id: u64,
+) -> Result<()> {
+    let accts = ctx.accounts;
+    accts.arena_state.settle_winner(&mut accts.user_bet_state)?;
+    let reward = accts.user_bet_state.reward;
+    let profit = reward as i64 - accts.user_bet_state.bet_amount as i64;
+    record_pnl(
+        &accts.period_config,
+        &mut ctx.remaining_accounts.iter(),
+        ctx.program_id,
+        accts.user.key,
+        accts.user_bet_state.bet_timestamp,
+        profit,
+    )?;
+
+    emit!(WinSettled {
+        arena_id,
+        user: accts.user.key(),
+        reward,
+        profit,
+    });
+    Ok(())
+}
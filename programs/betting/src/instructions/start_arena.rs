@@ -1,4 +1,4 @@
-use crate::{constants::*, error::*, instructions::*, states::*, utils::*};
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
 use anchor_lang::prelude::*;
 
 use pyth_client;
@@ -7,20 +7,22 @@ use pyth_client;
 #[instruction(arena_id: u64)]
 pub struct StartArena<'info> {
     #[account(mut)]
-    pub authority: Signer<'info>,
+    pub operator: Signer<'info>,
 
     #[account(
       seeds = [GLOBAL_STATE_SEED],
       bump,
-      has_one = authority,
-      has_one = sol_pyth_account
+      has_one = operator,
+      has_one = sol_pyth_account,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
     #[account(
       mut,
       seeds = [ARENA_STATE_SEED, &arena_id.to_le_bytes()],
-      bump
+      bump,
+      constraint = is_current_layout(&arena_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub arena_state: Box<Account<'info, ArenaState>>,
 
@@ -55,7 +57,12 @@ pub fn handler(ctx: Context<StartArena>, arena_id: u64) -> Result<()> {
     accts.arena_state.duration = accts.global_state.arena_duration;
     accts.arena_state.status = ArenaStatus::Started as u8;
 
-    msg!("locked price = {:?}", accts.arena_state.locked_price);
+    emit!(ArenaStarted {
+        arena_id,
+        locked_price: accts.arena_state.locked_price,
+        start_timestamp: current_time,
+        duration: accts.arena_state.duration,
+    });
 
     Ok(())
 }
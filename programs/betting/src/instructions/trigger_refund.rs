@@ -0,0 +1,65 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+#[instruction(arena_id: u64)]
+pub struct TriggerRefund<'info> {
+    pub user: Signer<'info>,
+
+    #[account(
+      seeds = [GLOBAL_STATE_SEED],
+      bump,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+      mut,
+      seeds = [ARENA_STATE_SEED, &arena_id.to_le_bytes()],
+      bump,
+      constraint = is_current_layout(&arena_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub arena_state: Box<Account<'info, ArenaState>>,
+
+    // only bettors of this arena can trigger refunds
+    #[account(
+      seeds = [USER_BET_SEED, user.key().as_ref(), &arena_id.to_le_bytes()],
+      bump,
+      constraint = is_current_layout(&user_bet_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub user_bet_state: Box<Account<'info, UserBetState>>,
+}
+
+impl<'info> TriggerRefund<'info> {
+    fn validate(&self) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        require!(
+            self.arena_state
+                .is_stalled(self.global_state.refund_grace_period, current_time),
+            BettingError::ArenaNotStalled
+        );
+        require!(
+            self.user_bet_state.bet_amount > 0,
+            BettingError::InvalidParameter
+        );
+        Ok(())
+    }
+}
+
+/// Put an arena the operator never settled or cancelled into refund mode.
+/// Afterwards every bettor gets their stake back through return_bet.
+#[access_control(ctx.accounts.validate())]
+pub fn handler(ctx: Context<TriggerRefund>, arena_id: u64) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+
+    let accts = ctx.accounts;
+    accts.arena_state.end_timestamp = current_time;
+    accts.arena_state.status = ArenaStatus::Refunding as u8;
+
+    emit!(RefundTriggered {
+        arena_id,
+        user: accts.user.key(),
+        timestamp: current_time,
+    });
+    Ok(())
+}
@@ -0,0 +1,56 @@
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_lang::prelude::*;
+
+#[derive(Accounts)]
+pub struct UpdateConfig<'info> {
+    pub authority: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [GLOBAL_STATE_SEED],
+        bump,
+        has_one = authority,
+        constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+}
+
+impl<'info> UpdateConfig<'info> {
+    fn validate(&self, config: &Config) -> Result<()> {
+        require!(
+            config.platform_fee_rate <= MAX_PLATFORM_FEE_RATE
+                && config.referral_fee_rate <= MAX_REF_FEE_RATE,
+            BettingError::ConfigOutOfBounds
+        );
+        require!(
+            config.arena_duration >= MIN_ARENA_DURATION
+                && config.arena_duration <= MAX_ARENA_DURATION,
+            BettingError::ConfigOutOfBounds
+        );
+        require!(
+            config.refund_grace_period >= MIN_REFUND_GRACE_PERIOD
+                && config.refund_grace_period <= MAX_REFUND_GRACE_PERIOD,
+            BettingError::ConfigOutOfBounds
+        );
+        require!(
+            config.treasury != Pubkey::default(),
+            BettingError::ConfigOutOfBounds
+        );
+        Ok(())
+    }
+}
+
+/// Update fee rates, arena duration, refund grace period, treasury and pyth accounts.
+/// The whole config is replaced, clients send the current values for fields they keep.
+#[access_control(ctx.accounts.validate(&config))]
+pub fn handler(ctx: Context<UpdateConfig>, config: Config) -> Result<()> {
+    let accts = ctx.accounts;
+    let old_config = accts.global_state.config();
+    accts.global_state.set_config(&config);
+
+    emit!(ConfigUpdated {
+        old_config,
+        new_config: config,
+    });
+    Ok(())
+}
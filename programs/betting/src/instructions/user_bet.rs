@@ -1,15 +1,11 @@
 use anchor_lang::prelude::*;
 
-use crate::{constants::*, error::*, states::*, utils::*};
-use anchor_spl::{
-    associated_token::AssociatedToken,
-    token::{self, Mint, Token, TokenAccount, Transfer},
-};
-
-use std::mem::size_of;
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_spl::associated_token::AssociatedToken;
+use std::slice::Iter;
 
 #[derive(Accounts)]
-#[instruction(arena_id: u64, b: u64, hour: u64, day: u64, week: u64, box_id: u64)]
+#[instruction(arena_id: u64)]
 pub struct UserBet<'info> {
     #[account(mut)]
     pub user: Signer<'info>,
@@ -17,7 +13,8 @@ pub struct UserBet<'info> {
     #[account(
       seeds = [GLOBAL_STATE_SEED],
       bump,
-      has_one = token_mint
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion,
+      constraint = !global_state.is_paused(PAUSE_BETTING) @ BettingError::BettingPaused
     )]
     pub global_state: Box<Account<'info, GlobalState>>,
 
@@ -25,13 +22,15 @@ pub struct UserBet<'info> {
       mut,
       seeds = [ARENA_STATE_SEED, &arena_id.to_le_bytes()],
       bump,
+      constraint = is_current_layout(&arena_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub arena_state: Box<Account<'info, ArenaState>>,
 
     #[account(
       mut,
       seeds = [USER_STATE_SEED, user.key().as_ref()],
-      bump
+      bump,
+      constraint = is_current_layout(&user_state) @ BettingError::UnsupportedAccountVersion
     )]
     pub user_state: Box<Account<'info, UserState>>,
 
@@ -40,54 +39,61 @@ pub struct UserBet<'info> {
       seeds = [USER_BET_SEED, user.key().as_ref(), &arena_id.to_le_bytes()],
       bump,
       payer = user,
-      space = 8 + size_of::<UserBetState>()
+      space = 8 + UserBetState::LEN
     )]
     pub user_bet_state: Account<'info, UserBetState>,
 
     #[account(
-        mut,
-        seeds = [EIGHT_BOX_STATE_SEED, user.key().as_ref(), &box_id.to_le_bytes()],
-        bump
-      )]
+      init_if_needed,
+      seeds = [EIGHT_BOX_STATE_SEED, user.key().as_ref(), &current_period_index(EIGHT_HOUR)?.to_le_bytes()],
+      bump,
+      payer = user,
+      space = 8 + EightBoxState::LEN
+    )]
     pub eight_box_state: Box<Account<'info, EightBoxState>>,
 
     #[account(
-      mut,
-      seeds = [HOUR_STATE_SEED, user.key().as_ref(), &hour.to_le_bytes()],
-      bump
+      seeds = [PERIOD_CONFIG_SEED],
+      bump,
+      constraint = is_current_layout(&period_config) @ BettingError::UnsupportedAccountVersion
     )]
-    pub user_hour_state: Box<Account<'info, HourState>>,
+    pub period_config: Box<Account<'info, PeriodConfig>>,
 
-    #[account(
-      mut,
-      seeds = [DAY_STATE_SEED, user.key().as_ref(), &day.to_le_bytes()],
-      bump
-    )]
-    pub user_day_state: Box<Account<'info, DayState>>,
+    /// CHECK: user's ATA of token_mint, the user wallet in SOL arenas. checked in handler
+    #[account(mut)]
+    pub user_ata: AccountInfo<'info>,
 
+    /// CHECK: global_state's ATA of token_mint under token_program
     #[account(
       mut,
-      seeds = [WEEK_STATE_SEED, user.key().as_ref(), &week.to_le_bytes()],
-      bump
+      address = associated_token_address(&global_state.key(), &token_mint.key(), token_program.key) @ BettingError::IncorrectTokenAccount
     )]
-    pub user_week_state: Box<Account<'info, WeekState>>,
+    pub escrow_ata: AccountInfo<'info>,
 
     #[account(
       mut,
-      associated_token::mint = token_mint,
-      associated_token::authority = user
+      seeds = [BET_MINT_SEED, token_mint.key().as_ref()],
+      bump,
+      constraint = is_current_layout(&bet_mint) @ BettingError::UnsupportedAccountVersion,
+      constraint = bet_mint.is_enabled == 1 @ BettingError::BetMintDisabled
     )]
-    pub user_ata: Account<'info, TokenAccount>,
+    pub bet_mint: Box<Account<'info, BetMint>>,
 
+    /// CHECK: mint of the arena, owned by token_program
     #[account(
-      mut,
-      associated_token::mint = token_mint,
-      associated_token::authority = global_state,
+      address = arena_state.mint_or(global_state.token_mint) @ BettingError::IncorrectMint,
+      owner = token_program.key() @ BettingError::IncorrectMint
     )]
-    pub escrow_ata: Account<'info, TokenAccount>,
-
-    pub token_mint: Account<'info, Mint>,
-    pub token_program: Program<'info, Token>,
+    pub token_mint: AccountInfo<'info>,
+    /// CHECK: default bet mint, volumes are counted in its units
+    #[account(address = global_state.token_mint @ BettingError::IncorrectMint)]
+    pub volume_mint: AccountInfo<'info>,
+    /// CHECK: SOL price feed, converts SOL stakes into volume
+    #[account(address = global_state.sol_pyth_account)]
+    pub sol_pyth_account: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022
+    #[account(constraint = is_token_program(token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub token_program: AccountInfo<'info>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub system_program: Program<'info, System>,
     pub rent: Sysvar<'info, Rent>,
@@ -95,9 +101,6 @@ pub struct UserBet<'info> {
 
 impl<'info> UserBet<'info> {
     fn validate(&self, ref_key: Pubkey, hash_key: [u8; 32]) -> Result<()> {
-        let current_time = Clock::get()?.unix_timestamp as u64;
-        // require!(current_time > )
-
         require!(
             self.arena_state.status == ArenaStatus::Opened as u8,
             BettingError::ArenaNotOpened
@@ -109,59 +112,256 @@ impl<'info> UserBet<'info> {
             );
         }
         assert_ref_hash(self.user.key(), ref_key, hash_key)?;
+        Ok(())
+    }
+    // period states are created on the first bet of each period,
+    // start times are taken from the clock, not from the client
+    fn init_period_states(&mut self, current_time: u64) -> Result<()> {
+        let user_key = self.user.key();
+        if self.eight_box_state.user == Pubkey::default() {
+            self.eight_box_state.version = EightBoxState::VERSION;
+            self.eight_box_state.user = user_key;
+            self.eight_box_state.start_time = current_time / EIGHT_HOUR * EIGHT_HOUR;
+        }
 
-        // validate hour, day, week states
         require!(
-            self.user_hour_state.start_time <= current_time
-                && self.user_hour_state.start_time + ONE_HOUR >= current_time,
-            BettingError::IncorrectHour
+            self.eight_box_state.version == EightBoxState::VERSION,
+            BettingError::UnsupportedAccountVersion
         );
 
+        // validate 8 hour box
         require!(
-            self.user_day_state.start_time <= current_time
-                && self.user_day_state.start_time + ONE_DAY >= current_time,
-            BettingError::IncorrectDay
+            self.eight_box_state.start_time <= current_time
+                && self.eight_box_state.start_time + EIGHT_HOUR > current_time,
+            BettingError::Incorrect8Hour
         );
-
+        Ok(())
+    }
+    // Every enabled period kind takes its user state and leaderboard from the remaining
+    // accounts, in kind order. They are created by the first bet of each period,
+    // bets worth no volume leave the entries untouched.
+    // Profit ranked kinds are published off chain and take no leaderboard.
+    fn record_periods(
+        &self,
+        rem_accts: &mut Iter<AccountInfo<'info>>,
+        program_id: &Pubkey,
+        current_time: u64,
+        volume: u64,
+    ) -> Result<()> {
+        for (kind, period_kind) in self.period_config.enabled_kinds() {
+            let period = period_kind.period_index(current_time)?;
+            let bet_amount = self.add_period_volume(
+                next_account_info(rem_accts)?,
+                program_id,
+                kind,
+                period_kind,
+                period,
+                volume,
+            )?;
+            if period_kind.is_profit_ranked() {
+                continue;
+            }
+            self.update_leaderboard(
+                next_account_info(rem_accts)?,
+                program_id,
+                kind,
+                period_kind,
+                period,
+                bet_amount,
+                volume,
+            )?;
+        }
+        Ok(())
+    }
+    // A running season follows the period accounts: season, user season state and
+    // season leaderboard. The user state is created by the first bet in the season.
+    fn record_season(
+        &self,
+        rem_accts: &mut Iter<AccountInfo<'info>>,
+        program_id: &Pubkey,
+        current_time: u64,
+        volume: u64,
+    ) -> Result<()> {
+        let season: Account<Season> = Account::try_from(next_account_info(rem_accts)?)?;
         require!(
-            self.user_week_state.start_time <= current_time
-                && self.user_week_state.start_time + ONE_WEEK >= current_time,
-            BettingError::IncorrectWeek
+            is_current_layout(&season),
+            BettingError::UnsupportedAccountVersion
         );
-
+        require!(
+            season.is_running(current_time),
+            BettingError::SeasonNotActive
+        );
+        let state_info = next_account_info(rem_accts)?;
+        let user_key = self.user.key();
+        let is_new = init_pda_if_needed(
+            self.user.to_account_info(),
+            state_info.clone(),
+            self.system_program.to_account_info(),
+            8 + SeasonState::LEN,
+            &[SEASON_STATE_SEED, &season.season_id.to_le_bytes(), user_key.as_ref()],
+            program_id,
+        )?;
+        let mut state: Account<SeasonState> = if is_new {
+            Account::try_from_unchecked(state_info)?
+        } else {
+            Account::try_from(state_info)?
+        };
+        if is_new {
+            state.version = SeasonState::VERSION;
+            state.season_id = season.season_id;
+            state.user = user_key;
+        }
+        require!(
+            is_current_layout(&state),
+            BettingError::UnsupportedAccountVersion
+        );
+        require!(
+            state.season_id == season.season_id && state.user == user_key,
+            BettingError::IncorrectPeriod
+        );
+        state.bet_amount = state.bet_amount.checked_add(volume).unwrap();
+        state.exit(program_id)?;
+        if volume > 0 {
+            season.record_score(
+                next_account_info(rem_accts)?,
+                program_id,
+                user_key,
+                state.score(season.win_bonus),
+            )?;
+        }
+        Ok(())
+    }
+    // returns the user's cumulative volume in the period
+    fn add_period_volume(
+        &self,
+        state_info: &AccountInfo<'info>,
+        program_id: &Pubkey,
+        kind: u8,
+        period_kind: &PeriodKind,
+        period: u64,
+        volume: u64,
+    ) -> Result<u64> {
+        let user_key = self.user.key();
+        let is_new = init_pda_if_needed(
+            self.user.to_account_info(),
+            state_info.clone(),
+            self.system_program.to_account_info(),
+            8 + PeriodState::LEN,
+            &[PERIOD_STATE_SEED, &[kind], user_key.as_ref(), &period.to_le_bytes()],
+            program_id,
+        )?;
+        let mut state: Account<PeriodState> = if is_new {
+            Account::try_from_unchecked(state_info)?
+        } else {
+            Account::try_from(state_info)?
+        };
+        if is_new {
+            state.version = PeriodState::VERSION;
+            state.kind = kind;
+            state.period = period;
+            state.user = user_key;
+            state.start_time = period_kind.start_time(period);
+        }
+        require!(
+            is_current_layout(&state),
+            BettingError::UnsupportedAccountVersion
+        );
+        require!(
+            state.kind == kind && state.period == period && state.user == user_key,
+            BettingError::IncorrectPeriod
+        );
+        state.bet_amount = state.bet_amount.checked_add(volume).unwrap();
+        state.exit(program_id)?;
+        Ok(state.bet_amount)
+    }
+    fn update_leaderboard(
+        &self,
+        board_info: &AccountInfo<'info>,
+        program_id: &Pubkey,
+        kind: u8,
+        period_kind: &PeriodKind,
+        period: u64,
+        bet_amount: u64,
+        volume: u64,
+    ) -> Result<()> {
+        let capacity = period_kind.leaderboard_size();
+        let is_new = init_pda_if_needed(
+            self.user.to_account_info(),
+            board_info.clone(),
+            self.system_program.to_account_info(),
+            PeriodLeaderboard::space(capacity),
+            &[PERIOD_LEADERBOARD_SEED, &[kind], &period.to_le_bytes()],
+            program_id,
+        )?;
+        let mut data = board_info.try_borrow_mut_data()?;
+        let (board, entries) = PeriodLeaderboard::from_data_mut(&mut data, is_new)?;
+        if is_new {
+            board.version = PeriodLeaderboard::VERSION;
+            board.kind = kind;
+            board.period = period;
+            board.capacity = capacity as u64;
+            board.payer = self.user.key();
+        }
+        require!(
+            board.version == PeriodLeaderboard::VERSION,
+            BettingError::UnsupportedAccountVersion
+        );
+        require!(
+            board.kind == kind && board.period == period,
+            BettingError::IncorrectPeriod
+        );
+        if volume > 0 {
+            record_volume(entries, &mut board.count, self.user.key(), bet_amount);
+        }
         Ok(())
     }
-    fn bet_context(&self) -> CpiContext<'_, '_, '_, 'info, Transfer<'info>> {
-        CpiContext::new(
+    // moves the bet into escrow, returns the stake after Token-2022 transfer fees
+    fn take_bet(&self, bet_amount: u64) -> Result<u64> {
+        if is_native_mint(self.token_mint.key) {
+            wrap_sol(
+                self.user.to_account_info(),
+                self.escrow_ata.to_account_info(),
+                self.system_program.to_account_info(),
+                bet_amount,
+            )?;
+            return Ok(bet_amount);
+        }
+        require_keys_eq!(
+            self.user_ata.key(),
+            associated_token_address(self.user.key, self.token_mint.key, self.token_program.key),
+            BettingError::IncorrectTokenAccount
+        );
+        transfer_checked(
             self.token_program.to_account_info(),
-            Transfer {
-                from: self.user_ata.to_account_info(),
-                to: self.escrow_ata.to_account_info(),
-                authority: self.user.to_account_info(),
-            },
+            self.user_ata.to_account_info(),
+            self.token_mint.to_account_info(),
+            self.escrow_ata.to_account_info(),
+            self.user.to_account_info(),
+            &[],
+            bet_amount,
         )
     }
 }
 
 #[access_control(ctx.accounts.validate(ref_key, hash_key))]
-pub fn handler(
-    ctx: Context<UserBet>,
+pub fn handler<'a, 'b, 'c, 'info>(
+    ctx: Context<'a, 'b, 'c, 'info, UserBet<'info>>,
     arena_id: u64,
     bet_amount: u64,
-    hour: u64,
-    day: u64,
-    week: u64,
-    box_id: u64,
     is_up: u8,
     ref_key: Pubkey,
     hash_key: [u8; 32],
 ) -> Result<()> {
     let current_time = Clock::get()?.unix_timestamp as u64;
     let accts = ctx.accounts;
+    accts.init_period_states(current_time)?;
+    let stake = accts.take_bet(bet_amount)?;
+
+    accts.user_bet_state.version = UserBetState::VERSION;
     accts.user_bet_state.user = accts.user.key();
     accts.user_bet_state.bet_timestamp = current_time;
     accts.user_bet_state.arena_id = arena_id;
-    accts.user_bet_state.bet_amount = bet_amount;
+    accts.user_bet_state.bet_amount = stake;
 
     if is_up == 0 {
         accts.user_bet_state.is_up = 0;
@@ -169,39 +369,56 @@ pub fn handler(
         accts.arena_state.down_amount = accts
             .arena_state
             .down_amount
-            .checked_add(bet_amount)
+            .checked_add(stake)
             .unwrap();
     } else {
         accts.user_bet_state.is_up = 1;
         accts.arena_state.up_count += 1;
-        accts.arena_state.up_amount = accts.arena_state.up_amount.checked_add(bet_amount).unwrap();
+        accts.arena_state.up_amount = accts.arena_state.up_amount.checked_add(stake).unwrap();
     };
     if accts.user_state.is_ref_inited == 0 {
         accts.user_state.is_ref_inited = 1;
         accts.user_state.referrer = ref_key;
     }
 
-    token::transfer(accts.bet_context(), bet_amount)?;
+    // SOL stakes count at their value in the default bet mint
+    let volume = if is_native_mint(accts.token_mint.key) {
+        sol_to_volume(
+            stake,
+            &accts.sol_pyth_account,
+            mint_decimals(&accts.volume_mint)?,
+        )?
+    } else {
+        stake
+    };
+    if accts.arena_state.is_booked == 1 {
+        accts.user_bet_state.is_booked = 1;
+        accts.bet_mint.open_stakes = accts
+            .bet_mint
+            .open_stakes
+            .checked_add(stake)
+            .unwrap();
+    }
+    assert_solvent(&accts.escrow_ata, accts.bet_mint.liabilities())?;
 
     accts.eight_box_state.bet_amount = accts
         .eight_box_state
         .bet_amount
-        .checked_add(bet_amount)
-        .unwrap();
-    accts.user_hour_state.bet_amount = accts
-        .user_hour_state
-        .bet_amount
-        .checked_add(bet_amount)
-        .unwrap();
-    accts.user_day_state.bet_amount = accts
-        .user_day_state
-        .bet_amount
-        .checked_add(bet_amount)
-        .unwrap();
-    accts.user_week_state.bet_amount = accts
-        .user_week_state
-        .bet_amount
-        .checked_add(bet_amount)
+        .checked_add(volume)
         .unwrap();
+    let rem_accts = &mut ctx.remaining_accounts.iter();
+    accts.record_periods(rem_accts, ctx.program_id, current_time, volume)?;
+    if rem_accts.len() > 0 {
+        accts.record_season(rem_accts, ctx.program_id, current_time, volume)?;
+    }
+
+    emit!(BetPlaced {
+        arena_id,
+        user: accts.user.key(),
+        amount: stake,
+        is_up: accts.user_bet_state.is_up,
+        referrer: accts.user_state.referrer,
+        timestamp: current_time,
+    });
     Ok(())
 }
@@ -0,0 +1,114 @@
+use anchor_lang::{prelude::*, AccountsClose};
+
+use crate::{constants::*, error::*, events::*, states::*, utils::*};
+use anchor_spl::associated_token::AssociatedToken;
+
+#[derive(Accounts)]
+pub struct WithdrawVested<'info> {
+    #[account(mut)]
+    pub user: Signer<'info>,
+
+    #[account(
+      seeds = [GLOBAL_STATE_SEED],
+      bump,
+      has_one = rank_mint,
+      constraint = is_current_layout(&global_state) @ BettingError::UnsupportedAccountVersion,
+      constraint = !global_state.is_paused(PAUSE_CLAIMS) @ BettingError::ClaimsPaused
+    )]
+    pub global_state: Box<Account<'info, GlobalState>>,
+
+    #[account(
+      mut,
+      seeds = [VESTING_SEED, vesting.period_result.as_ref(), user.key().as_ref()],
+      bump,
+      has_one = user,
+      constraint = is_current_layout(&vesting) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub vesting: Box<Account<'info, Vesting>>,
+
+    /// CHECK: vesting's ATA of rank_mint
+    #[account(
+      mut,
+      address = associated_token_address(&vesting.key(), &rank_mint.key(), feel_token_program.key) @ BettingError::IncorrectTokenAccount
+    )]
+    pub vesting_vault: AccountInfo<'info>,
+
+    /// CHECK: user's ATA of rank_mint, created in handler if missing
+    #[account(mut)]
+    pub user_feel_ata: AccountInfo<'info>,
+
+    /// CHECK: FEEL mint, owned by feel_token_program
+    #[account(owner = feel_token_program.key() @ BettingError::IncorrectMint)]
+    pub rank_mint: AccountInfo<'info>,
+    /// CHECK: token program or Token-2022 of the FEEL mint
+    #[account(constraint = is_token_program(feel_token_program.key) @ BettingError::IncorrectTokenProgram)]
+    pub feel_token_program: AccountInfo<'info>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub system_program: Program<'info, System>,
+    pub rent: Sysvar<'info, Rent>,
+}
+
+impl<'info> WithdrawVested<'info> {
+    fn validate(&self) -> Result<()> {
+        let current_time = Clock::get()?.unix_timestamp as u64;
+        require!(
+            self.vesting.withdrawable(current_time) > 0 || self.vesting.total == self.vesting.withdrawn,
+            BettingError::UnableToClaim
+        );
+        Ok(())
+    }
+}
+
+/// Withdraw the FEEL vested so far, the vesting account and its vault are closed once drained.
+#[access_control(ctx.accounts.validate())]
+pub fn handler(ctx: Context<WithdrawVested>) -> Result<()> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    let accts = ctx.accounts;
+    let amount = accts.vesting.withdrawable(current_time);
+    accts.vesting.withdrawn += amount;
+
+    let vesting_seeds = &[
+        VESTING_SEED,
+        accts.vesting.period_result.as_ref(),
+        accts.user.key.as_ref(),
+        &[*(ctx.bumps.get("vesting").unwrap())],
+    ];
+    create_ata_if_needed(
+        accts.user.to_account_info(),
+        accts.user_feel_ata.to_account_info(),
+        accts.user.to_account_info(),
+        accts.rank_mint.to_account_info(),
+        accts.system_program.to_account_info(),
+        accts.feel_token_program.to_account_info(),
+        accts.rent.to_account_info(),
+    )?;
+    let received = transfer_checked(
+        accts.feel_token_program.to_account_info(),
+        accts.vesting_vault.to_account_info(),
+        accts.rank_mint.to_account_info(),
+        accts.user_feel_ata.to_account_info(),
+        accts.vesting.to_account_info(),
+        &[vesting_seeds],
+        amount,
+    )?;
+
+    let remaining = accts.vesting.total - accts.vesting.withdrawn;
+    emit!(VestedWithdrawn {
+        user: accts.user.key(),
+        vesting: accts.vesting.key(),
+        amount: received,
+        remaining,
+    });
+
+    if remaining == 0 {
+        close_token_account(
+            accts.feel_token_program.to_account_info(),
+            accts.vesting_vault.to_account_info(),
+            accts.user.to_account_info(),
+            accts.vesting.to_account_info(),
+            &[vesting_seeds],
+        )?;
+        accts.vesting.close(accts.user.to_account_info())?;
+    }
+    Ok(())
+}
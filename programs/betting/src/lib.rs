@@ -6,6 +6,8 @@ declare_id!("F9cCsF2K73VcydesyJnk2GNA6igt82Roo4CQDkkk7dN");
 pub mod constants;
 /// error
 pub mod error;
+/// events
+pub mod events;
 /// instructions
 pub mod instructions;
 /// states
@@ -16,6 +18,7 @@ pub mod utils;
 pub mod views;
 
 use crate::instructions::*;
+use crate::states::{Config, EightBoxPrize, MerkleRoot, PeriodTier, RankProof, Roles};
 use crate::views::*;
 
 #[program]
@@ -38,6 +41,66 @@ pub mod betting {
         )
     }
 
+    pub fn update_config(ctx: Context<UpdateConfig>, config: Config) -> Result<()> {
+        update_config::handler(ctx, config)
+    }
+
+    pub fn propose_authority(ctx: Context<ProposeAuthority>, new_authority: Pubkey) -> Result<()> {
+        propose_authority::handler(ctx, new_authority)
+    }
+
+    pub fn accept_authority(ctx: Context<AcceptAuthority>) -> Result<()> {
+        accept_authority::handler(ctx)
+    }
+
+    pub fn set_roles(ctx: Context<SetRoles>, roles: Roles) -> Result<()> {
+        set_roles::handler(ctx, roles)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: u8) -> Result<()> {
+        set_paused::handler(ctx, paused)
+    }
+
+    pub fn set_bet_mint(ctx: Context<SetBetMint>, is_enabled: u8) -> Result<()> {
+        set_bet_mint::handler(ctx, is_enabled)
+    }
+
+    pub fn set_fee_split(
+        ctx: Context<SetFeeSplit>,
+        recipients: Vec<Pubkey>,
+        shares: Vec<u64>,
+    ) -> Result<()> {
+        set_fee_split::handler(ctx, recipients, shares)
+    }
+
+    pub fn set_period_kind(
+        ctx: Context<SetPeriodKind>,
+        kind: u8,
+        length: u64,
+        epoch_offset: u64,
+        is_enabled: u8,
+        ranking: u8,
+        claim_window: u64,
+        tiers: Vec<PeriodTier>,
+    ) -> Result<()> {
+        set_period_kind::handler(
+            ctx,
+            kind,
+            length,
+            epoch_offset,
+            is_enabled,
+            ranking,
+            claim_window,
+            tiers,
+        )
+    }
+
+    pub fn distribute_fees<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, DistributeFees<'info>>,
+    ) -> Result<()> {
+        distribute_fees::handler(ctx)
+    }
+
     pub fn open_arena(ctx: Context<OpenArena>, arena_id: u64) -> Result<()> {
         open_arena::handler(ctx, arena_id)
     }
@@ -50,35 +113,53 @@ pub mod betting {
         cancel_arena::handler(ctx, arena_id)
     }
 
-    pub fn user_bet(
-        ctx: Context<UserBet>,
+    pub fn user_bet<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, UserBet<'info>>,
         arena_id: u64,
         bet_amount: u64,
-        hour: u64,
-        day: u64,
-        week: u64,
-        box_id: u64,
         bet_side: u8,
         ref_key: Pubkey,
         hash_key: [u8; 32],
     ) -> Result<()> {
-        user_bet::handler(
-            ctx, arena_id, bet_amount, hour, day, week, box_id, bet_side, ref_key, hash_key,
-        )
+        user_bet::handler(ctx, arena_id, bet_amount, bet_side, ref_key, hash_key)
     }
 
-    pub fn end_arena(ctx: Context<EndArena>, arena_id: u64) -> Result<()> {
+    pub fn end_arena<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, EndArena<'info>>,
+        arena_id: u64,
+    ) -> Result<()> {
         end_arena::handler(ctx, arena_id)
     }
 
-    pub fn claim_reward(ctx: Context<ClaimReward>, arena_id: u64) -> Result<()> {
+    pub fn claim_reward<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimReward<'info>>,
+        arena_id: u64,
+    ) -> Result<()> {
         claim_reward::handler(ctx, arena_id)
     }
 
+    pub fn settle_loss<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SettleLoss<'info>>,
+        arena_id: u64,
+    ) -> Result<()> {
+        settle_loss::handler(ctx, arena_id)
+    }
+
+    pub fn settle_win<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, SettleWin<'info>>,
+        arena_id: u64,
+    ) -> Result<()> {
+        settle_win::handler(ctx, arena_id)
+    }
+
     pub fn return_bet(ctx: Context<ReturnBet>, arena_id: u64) -> Result<()> {
         return_bet::handler(ctx, arena_id)
     }
 
+    pub fn trigger_refund(ctx: Context<TriggerRefund>, arena_id: u64) -> Result<()> {
+        trigger_refund::handler(ctx, arena_id)
+    }
+
     pub fn init_user_state(ctx: Context<InitUserState>, user_key: Pubkey) -> Result<()> {
         init_user_state::handler(ctx, user_key)
     }
@@ -99,16 +180,8 @@ pub mod betting {
         buy_nft::handler(ctx)
     }
 
-    pub fn init_hour_state(ctx: Context<InitHourState>, user_key: Pubkey, hour: u64) -> Result<()> {
-        init_hour_state::handler(ctx, user_key, hour)
-    }
-
-    pub fn init_day_state(ctx: Context<InitDayState>, user_key: Pubkey, day: u64) -> Result<()> {
-        init_day_state::handler(ctx, user_key, day)
-    }
-
-    pub fn init_week_state(ctx: Context<InitWeekState>, user_key: Pubkey, week: u64) -> Result<()> {
-        init_week_state::handler(ctx, user_key, week)
+    pub fn init_period_state(ctx: Context<InitPeriodState>, kind: u8, user_key: Pubkey) -> Result<()> {
+        init_period_state::handler(ctx, kind, user_key)
     }
 
     pub fn init_eight_box_state(
@@ -119,31 +192,22 @@ pub mod betting {
         init_eight_box_state::handler(ctx, user_key, box_id)
     }
 
-    pub fn end_hour(
-        ctx: Context<EndHour>,
-        hour: u64,
-        tiers: [u64; 5],
-        rewards: [u64; 5],
-    ) -> Result<()> {
-        end_hour::handler(ctx, hour, tiers, rewards)
-    }
-
-    pub fn end_day(
-        ctx: Context<EndDay>,
-        day: u64,
-        tiers: [u64; 7],
-        rewards: [u64; 7],
+    pub fn end_period(
+        ctx: Context<EndPeriod>,
+        kind: u8,
+        period: u64,
+        rewards: Vec<u64>,
+        merkle_root: Option<MerkleRoot>,
     ) -> Result<()> {
-        end_day::handler(ctx, day, tiers, rewards)
+        end_period::handler(ctx, kind, period, rewards, merkle_root)
     }
 
-    pub fn end_week(
-        ctx: Context<EndWeek>,
-        week: u64,
-        tiers: [u64; 9],
-        rewards: [u64; 9],
+    pub fn set_eight_box_prizes(
+        ctx: Context<SetEightBoxPrizes>,
+        prizes: Vec<EightBoxPrize>,
+        claim_window: u64,
     ) -> Result<()> {
-        end_week::handler(ctx, week, tiers, rewards)
+        set_eight_box_prizes::handler(ctx, prizes, claim_window)
     }
 
     pub fn claim_eight_box<'a, 'b, 'c, 'info>(
@@ -154,25 +218,66 @@ pub mod betting {
         claim_eight_box::handler(ctx, box_id, prize_id)
     }
 
-    pub fn claim_hour_rank_reward<'a, 'b, 'c, 'info>(
-        ctx: Context<'a, 'b, 'c, 'info, ClaimHourRankReward<'info>>,
-        hour: u64,
+    pub fn claim_all_eight_box<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimEightBox<'info>>,
+        box_id: u64,
     ) -> Result<()> {
-        claim_hour_rank_reward::handler(ctx, hour)
+        claim_eight_box::claim_all_handler(ctx, box_id)
     }
 
-    pub fn claim_day_rank_reward<'a, 'b, 'c, 'info>(
-        ctx: Context<'a, 'b, 'c, 'info, ClaimDayRankReward<'info>>,
-        day: u64,
+    pub fn claim_rank_reward<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimRankReward<'info>>,
+        kind: u8,
+        period: u64,
+        proof: Option<RankProof>,
     ) -> Result<()> {
-        claim_day_rank_reward::handler(ctx, day)
+        claim_rank_reward::handler(ctx, kind, period, proof)
     }
 
-    pub fn claim_week_rank_reward<'a, 'b, 'c, 'info>(
-        ctx: Context<'a, 'b, 'c, 'info, ClaimWeekRankReward<'info>>,
-        week: u64,
+    pub fn claim_legacy_rank_reward<'a, 'b, 'c, 'info>(
+        ctx: Context<'a, 'b, 'c, 'info, ClaimLegacyRankReward<'info>>,
+        legacy_kind: u8,
+        period: u64,
     ) -> Result<()> {
-        claim_week_rank_reward::handler(ctx, week)
+        claim_legacy_rank_reward::handler(ctx, legacy_kind, period)
+    }
+
+    pub fn set_period_vesting(
+        ctx: Context<SetPeriodVesting>,
+        kind: u8,
+        duration: u64,
+        cliff: u64,
+    ) -> Result<()> {
+        set_period_vesting::handler(ctx, kind, duration, cliff)
+    }
+
+    pub fn set_period_pool(ctx: Context<SetPeriodPool>, kind: u8, fee_rate: u16) -> Result<()> {
+        set_period_pool::handler(ctx, kind, fee_rate)
+    }
+
+    pub fn withdraw_vested(ctx: Context<WithdrawVested>) -> Result<()> {
+        withdraw_vested::handler(ctx)
+    }
+
+    pub fn create_season(
+        ctx: Context<CreateSeason>,
+        season_id: u64,
+        start_time: u64,
+        end_time: u64,
+        win_bonus: u64,
+        claim_window: u64,
+        name: String,
+        uri: String,
+    ) -> Result<()> {
+        create_season::handler(ctx, season_id, start_time, end_time, win_bonus, claim_window, name, uri)
+    }
+
+    pub fn end_season(ctx: Context<EndSeason>, season_id: u64, rewards: Vec<u64>) -> Result<()> {
+        end_season::handler(ctx, season_id, rewards)
+    }
+
+    pub fn claim_season_trophy(ctx: Context<ClaimSeasonTrophy>, season_id: u64) -> Result<()> {
+        claim_season_trophy::handler(ctx, season_id)
     }
 
     pub fn mint_fragment<'a, 'b, 'c, 'info>(
@@ -213,29 +318,17 @@ pub mod betting {
         create_fragment_mints::handler(ctx)
     }
 
-    pub fn get_hour_rank(ctx: Context<GetHourRank>) -> Result<u8> {
-        get_hour_rank::handler(ctx)
-    }
-
-    pub fn get_day_rank(ctx: Context<GetDayRank>) -> Result<u8> {
-        get_day_rank::handler(ctx)
+    pub fn get_rank(ctx: Context<GetRank>, proof: Option<RankProof>) -> Result<RankView> {
+        get_rank::handler(ctx, proof)
     }
 
-    pub fn get_week_rank(ctx: Context<GetWeekRank>) -> Result<u8> {
-        get_week_rank::handler(ctx)
+    pub fn migrate_account(ctx: Context<MigrateAccount>) -> Result<()> {
+        migrate_account::handler(ctx)
     }
 
     // close accounts
-    pub fn close_hour_result(ctx: Context<CloseHourResult>) -> Result<()> {
-      close_hour_result::handler(ctx)
-    }
-    
-    pub fn close_day_result(ctx: Context<CloseDayResult>) -> Result<()> {
-      close_day_result::handler(ctx)
-    }
-    
-    pub fn close_week_result(ctx: Context<CloseWeekResult>) -> Result<()> {
-      close_week_result::handler(ctx)
+    pub fn close_period_result(ctx: Context<ClosePeriodResult>) -> Result<()> {
+      close_period_result::handler(ctx)
     }
 
     pub fn close_arena_state(ctx: Context<CloseArenaState>) -> Result<()> {
@@ -245,6 +338,26 @@ pub mod betting {
     pub fn close_eight_box_state(ctx: Context<CloseEightBoxState>) -> Result<()> {
       close_eight_box_state::handler(ctx)
     }
+
+    pub fn close_period_leaderboard(ctx: Context<ClosePeriodLeaderboard>) -> Result<()> {
+      close_period_leaderboard::handler(ctx)
+    }
+
+    pub fn close_period_state(ctx: Context<ClosePeriodState>) -> Result<()> {
+      close_period_state::handler(ctx)
+    }
+
+    pub fn close_season(ctx: Context<CloseSeason>) -> Result<()> {
+      close_season::handler(ctx)
+    }
+
+    pub fn close_legacy_result(
+        ctx: Context<CloseLegacyResult>,
+        legacy_kind: u8,
+        period: u64,
+    ) -> Result<()> {
+      close_legacy_result::handler(ctx, legacy_kind, period)
+    }
     
     
 }
@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::{constants::*, error::*, states::{UserBetState, Versioned}};
+
 #[account]
 #[derive(Default)]
 pub struct ArenaState {
+    pub version: u8,
     pub arena_id: u64,
     pub locked_price: u64,
     pub start_timestamp: u64,
@@ -19,5 +22,131 @@ pub struct ArenaState {
     pub end_timestamp: u64,
     pub bet_result: u8,
 
-    pub reserves: [u64; 4],
+    // currency of this arena, default for arenas opened before multi mint support
+    pub bet_mint: Pubkey,
+
+    // winnings and referral fees booked for the winning side at settlement,
+    // and how many winning bets claimed how much of them
+    pub winnings: u64,
+    pub referral_fees: u64,
+    pub winners_claimed: u64,
+    pub winnings_claimed: u64,
+    pub referral_fees_claimed: u64,
+
+    // set by open_arena, stakes and winnings of older arenas were never booked on the BetMint
+    pub is_booked: u8,
+}
+
+impl Versioned for ArenaState {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1 + 8 * 4 + 1 + 8 * 4 + 8 * 2 + 1 + 32 + 8 * 5 + 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl ArenaState {
+    /// bet mint of the arena, arenas opened before multi mint support bet in `default_mint`
+    pub fn mint_or(&self, default_mint: Pubkey) -> Pubkey {
+        if self.bet_mint == Pubkey::default() {
+            default_mint
+        } else {
+            self.bet_mint
+        }
+    }
+
+    /// Start the refund deadline of a live arena that has no timestamp at `current_time`,
+    /// so pre-upgrade arenas can still be refunded once they stall.
+    pub fn stamp_missing_start(&mut self, current_time: u64) {
+        let is_live = self.status == ArenaStatus::Opened as u8
+            || self.status == ArenaStatus::Started as u8;
+        if is_live && self.start_timestamp == 0 {
+            self.start_timestamp = current_time;
+        }
+    }
+
+    /// true if the arena was neither settled nor cancelled within its duration plus `grace_period`
+    pub fn is_stalled(&self, grace_period: u64, current_time: u64) -> bool {
+        let is_live = self.status == ArenaStatus::Opened as u8
+            || self.status == ArenaStatus::Started as u8;
+        // migrate_account stamps arenas opened before open_arena recorded a timestamp
+        if !is_live {
+            return false;
+        }
+        let deadline = self
+            .start_timestamp
+            .saturating_add(self.duration)
+            .saturating_add(grace_period.max(MIN_REFUND_GRACE_PERIOD));
+        current_time >= deadline
+    }
+
+    /// Book the winnings and referral fees of a successful arena,
+    /// returns the platform fee less the referral fees.
+    pub fn book_winnings(&mut self, platform_fee_rate: u64, referral_fee_rate: u64) -> u64 {
+        let bet_total_amount = self.up_amount.checked_add(self.down_amount).unwrap();
+        let platform_fee = (bet_total_amount as u128)
+            .checked_mul(platform_fee_rate as u128)
+            .unwrap()
+            .checked_div(FEE_RATE_DENOMINATOR as u128)
+            .unwrap() as u64;
+        let ref_fee = (platform_fee as u128)
+            .checked_mul(referral_fee_rate as u128)
+            .unwrap()
+            .checked_div(FEE_RATE_DENOMINATOR as u128)
+            .unwrap() as u64;
+        self.winnings = bet_total_amount.checked_sub(platform_fee).unwrap();
+        self.referral_fees = ref_fee;
+        platform_fee.checked_sub(ref_fee).unwrap()
+    }
+
+    /// number of bets and stakes of the side that won
+    pub fn winning_side(&self) -> (u64, u64) {
+        if self.bet_result == 0 {
+            (self.down_count, self.down_amount)
+        } else {
+            (self.up_count, self.up_amount)
+        }
+    }
+
+    /// Winnings and referral fee of a winning bet, the stake's share of what was booked rounded down.
+    /// The last winning bet gets what rounding left, so the arena pays out exactly what it booked.
+    pub fn winner_share(&self, bet_amount: u64) -> Result<(u64, u64)> {
+        let (winner_count, winning_stakes) = self.winning_side();
+        if self.winners_claimed.checked_add(1).unwrap() >= winner_count {
+            return Ok((
+                self.winnings
+                    .checked_sub(self.winnings_claimed)
+                    .ok_or(BettingError::LiabilityUnderflow)?,
+                self.referral_fees
+                    .checked_sub(self.referral_fees_claimed)
+                    .ok_or(BettingError::LiabilityUnderflow)?,
+            ));
+        }
+        let share = |amount: u64| {
+            (amount as u128)
+                .checked_mul(bet_amount as u128)
+                .unwrap()
+                .checked_div(winning_stakes as u128)
+                .unwrap() as u64
+        };
+        Ok((share(self.winnings), share(self.referral_fees)))
+    }
+
+    pub fn record_winner_claim(&mut self, winnings: u64, referral_fee: u64) {
+        self.winners_claimed = self.winners_claimed.checked_add(1).unwrap();
+        self.winnings_claimed = self.winnings_claimed.checked_add(winnings).unwrap();
+        self.referral_fees_claimed = self.referral_fees_claimed.checked_add(referral_fee).unwrap();
+    }
+
+    /// Book the share of a winning bet once, settle_win and claim_reward both go through here
+    /// so the PnL booked at settlement is what the claim pays.
+    pub fn settle_winner(&mut self, user_bet_state: &mut UserBetState) -> Result<()> {
+        let (reward, referral_fee) = self.winner_share(user_bet_state.bet_amount)?;
+        self.record_winner_claim(reward, referral_fee);
+        user_bet_state.is_settled = 1;
+        user_bet_state.reward = reward;
+        user_bet_state.referral_fee = referral_fee;
+        Ok(())
+    }
 }
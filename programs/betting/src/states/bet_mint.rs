@@ -0,0 +1,38 @@
+use anchor_lang::prelude::*;
+
+use crate::states::Versioned;
+
+/// allow-list entry of a betting currency, its escrow is the global_state ATA of `mint`
+#[account]
+#[derive(Default)]
+pub struct BetMint {
+    pub version: u8,
+    pub mint: Pubkey,
+    // new arenas and bets need an enabled mint, claims and refunds always work
+    pub is_enabled: u8,
+
+    // what the escrow of this mint owes, see liabilities
+    pub open_stakes: u64,
+    pub unclaimed_winnings: u64,
+    pub unclaimed_referral_fees: u64,
+}
+
+impl Versioned for BetMint {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1 + 32 + 1 + 8 * 3;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl BetMint {
+    /// stakes of unsettled arenas plus winnings and referral fees not claimed yet
+    pub fn liabilities(&self) -> u64 {
+        self.open_stakes
+            .checked_add(self.unclaimed_winnings)
+            .unwrap()
+            .checked_add(self.unclaimed_referral_fees)
+            .unwrap()
+    }
+}
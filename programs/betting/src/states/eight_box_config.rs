@@ -0,0 +1,51 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, states::Versioned};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct EightBoxPrize {
+    // box volume that unlocks the prize
+    pub volume: u64,
+    // EightBoxPrizeKind
+    pub kind: u8,
+    // bundle id of a bundle prize, fragment number of a fragment prize
+    pub id: u8,
+    // FEEL of a FEEL prize, fragments minted for a fragment prize
+    pub amount: u64,
+}
+
+impl EightBoxPrize {
+    pub const LEN: usize = 8 + 1 + 1 + 8;
+}
+
+/// Eight-hour box prizes defined by the authority, a prize is identified by its index.
+#[account]
+#[derive(Default)]
+pub struct EightBoxConfig {
+    pub version: u8,
+    pub prize_count: u8,
+    pub prizes: [EightBoxPrize; MAX_EIGHT_BOX_PRIZES],
+    // boxes can be claimed for this long after their window, then closed
+    pub claim_window: u64,
+}
+
+impl Versioned for EightBoxConfig {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1 + 1 + EightBoxPrize::LEN * MAX_EIGHT_BOX_PRIZES + 8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl EightBoxConfig {
+    pub fn prizes(&self) -> &[EightBoxPrize] {
+        &self.prizes[..self.prize_count as usize]
+    }
+
+    pub fn prize(&self, prize_id: u8) -> Result<&EightBoxPrize> {
+        self.prizes()
+            .get(prize_id as usize)
+            .ok_or_else(|| error!(BettingError::InvalidParameter))
+    }
+}
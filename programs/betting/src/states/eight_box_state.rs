@@ -1,10 +1,34 @@
 use anchor_lang::prelude::*;
 
+use crate::{constants::*, states::{EightBoxConfig, Versioned}};
+
 #[account]
 #[derive(Default)]
 pub struct EightBoxState {
+    pub version: u8,
     pub user: Pubkey,
     pub start_time: u64,
     pub bet_amount: u64,
-    pub claimed_status: u8,
+    // bit i set once prize i of the config is claimed
+    pub claimed_status: u64,
+}
+
+impl Versioned for EightBoxState {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1 + 32 + 8 * 2 + 8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl EightBoxState {
+    /// prizes stay claimable once the window is over, until this time
+    pub fn claim_deadline(&self, config: &EightBoxConfig) -> u64 {
+        self.start_time + EIGHT_HOUR + config.claim_window
+    }
+
+    pub fn is_claimable(&self, config: &EightBoxConfig, timestamp: u64) -> bool {
+        timestamp <= self.claim_deadline(config)
+    }
 }
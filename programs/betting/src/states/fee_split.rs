@@ -0,0 +1,23 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, states::Versioned};
+
+#[account]
+#[derive(Default)]
+pub struct FeeSplit {
+    pub version: u8,
+    pub recipient_count: u8,
+    // wallets whose ATA of each bet mint is paid by distribute_fees
+    pub recipients: [Pubkey; MAX_FEE_RECIPIENTS],
+    // basis points of each recipient, the used slots sum to FEE_RATE_DENOMINATOR
+    pub shares: [u64; MAX_FEE_RECIPIENTS],
+}
+
+impl Versioned for FeeSplit {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1 + 1 + (32 + 8) * MAX_FEE_RECIPIENTS;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
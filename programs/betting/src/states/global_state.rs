@@ -1,10 +1,15 @@
 use anchor_lang::prelude::*;
 
+use crate::states::Versioned;
+
 #[account]
 #[derive(Default)]
 pub struct GlobalState {
+    pub version: u8,
     pub is_initialized: u8,
     pub authority: Pubkey,
+    // set by propose_authority, becomes authority on accept_authority
+    pub pending_authority: Pubkey,
     pub treasury: Pubkey,
     
     pub btc_pyth_account: Pubkey,
@@ -19,5 +24,108 @@ pub struct GlobalState {
     pub platform_fee_rate: u64,
     pub referral_fee_rate: u64,
 
-    pub reserves: [u64; 12],
+    // keys allowed to run each group of admin instructions, see set_roles
+    pub operator: Pubkey,
+    pub ranker: Pubkey,
+    pub minter: Pubkey,
+    pub treasurer: Pubkey,
+    pub pauser: Pubkey,
+
+    // PAUSE_* bits, set by the pauser
+    pub paused: u8,
+
+    // seconds after an arena's end time before bettors may force refunds
+    pub refund_grace_period: u64,
+}
+
+impl Versioned for GlobalState {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1 + 1 + 32 * 10 + 8 * 3 + 32 * 5 + 1 + 8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+/// admin adjustable part of the global state
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct Config {
+    pub treasury: Pubkey,
+
+    pub btc_pyth_account: Pubkey,
+    pub eth_pyth_account: Pubkey,
+    pub sol_pyth_account: Pubkey,
+    pub avax_pyth_account: Pubkey,
+    pub ada_pyth_account: Pubkey,
+
+    pub arena_duration: u64,
+    pub platform_fee_rate: u64,
+    pub referral_fee_rate: u64,
+    pub refund_grace_period: u64,
+}
+
+/// operator: open, start, end and cancel arenas
+/// ranker: publish hour, day and week results
+/// minter: fragment mints
+/// treasurer: close finished accounts
+/// pauser: pause switches
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct Roles {
+    pub operator: Pubkey,
+    pub ranker: Pubkey,
+    pub minter: Pubkey,
+    pub treasurer: Pubkey,
+    pub pauser: Pubkey,
+}
+
+impl GlobalState {
+    pub fn config(&self) -> Config {
+        Config {
+            treasury: self.treasury,
+            btc_pyth_account: self.btc_pyth_account,
+            eth_pyth_account: self.eth_pyth_account,
+            sol_pyth_account: self.sol_pyth_account,
+            avax_pyth_account: self.avax_pyth_account,
+            ada_pyth_account: self.ada_pyth_account,
+            arena_duration: self.arena_duration,
+            platform_fee_rate: self.platform_fee_rate,
+            referral_fee_rate: self.referral_fee_rate,
+            refund_grace_period: self.refund_grace_period,
+        }
+    }
+
+    pub fn set_config(&mut self, config: &Config) {
+        self.treasury = config.treasury;
+        self.btc_pyth_account = config.btc_pyth_account;
+        self.eth_pyth_account = config.eth_pyth_account;
+        self.sol_pyth_account = config.sol_pyth_account;
+        self.avax_pyth_account = config.avax_pyth_account;
+        self.ada_pyth_account = config.ada_pyth_account;
+        self.arena_duration = config.arena_duration;
+        self.platform_fee_rate = config.platform_fee_rate;
+        self.referral_fee_rate = config.referral_fee_rate;
+        self.refund_grace_period = config.refund_grace_period;
+    }
+
+    pub fn is_paused(&self, feature: u8) -> bool {
+        self.paused & feature != 0
+    }
+
+    pub fn roles(&self) -> Roles {
+        Roles {
+            operator: self.operator,
+            ranker: self.ranker,
+            minter: self.minter,
+            treasurer: self.treasurer,
+            pauser: self.pauser,
+        }
+    }
+
+    pub fn set_roles(&mut self, roles: &Roles) {
+        self.operator = roles.operator;
+        self.ranker = roles.ranker;
+        self.minter = roles.minter;
+        self.treasurer = roles.treasurer;
+        self.pauser = roles.pauser;
+    }
 }
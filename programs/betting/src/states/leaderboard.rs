@@ -0,0 +1,174 @@
+use anchor_lang::{prelude::*, Discriminator};
+
+use crate::{
+    constants::*,
+    error::*,
+    states::{PeriodTier, Versioned},
+};
+
+use std::mem::size_of;
+
+#[zero_copy]
+pub struct LeaderboardEntry {
+    pub user: Pubkey,
+    pub volume: u64,
+}
+
+// entries trail the zero copy header, cast the same way anchor casts the header
+unsafe impl bytemuck::Pod for LeaderboardEntry {}
+unsafe impl bytemuck::Zeroable for LeaderboardEntry {}
+
+/// Top bettors of one period by volume, kept sorted by user_bet.
+/// The header is followed by `capacity` entries, the leaderboard size of the kind.
+#[account(zero_copy)]
+pub struct PeriodLeaderboard {
+    pub version: u8,
+    pub kind: u8,
+    pub padding: [u8; 6],
+    pub period: u64,
+    pub count: u64,
+    pub capacity: u64,
+    // paid the rent when the board was created, gets it back when it is closed
+    pub payer: Pubkey,
+}
+
+impl Versioned for PeriodLeaderboard {
+    const VERSION: u8 = 1;
+    // header only, see `space`
+    const LEN: usize = size_of::<PeriodLeaderboard>();
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl PeriodLeaderboard {
+    pub fn space(capacity: usize) -> usize {
+        8 + Self::LEN + capacity * size_of::<LeaderboardEntry>()
+    }
+
+    /// header and entries of a leaderboard account's data
+    pub fn from_data(data: &[u8]) -> Result<(&Self, &[LeaderboardEntry])> {
+        require!(
+            data.len() >= 8 + Self::LEN && data[..8] == Self::discriminator(),
+            BettingError::IncorrectPeriod
+        );
+        let (header, entries) = data[8..].split_at(Self::LEN);
+        let header: &Self = bytemuck::try_from_bytes(header)
+            .map_err(|_| error!(BettingError::IncorrectPeriod))?;
+        let entries: &[LeaderboardEntry] = bytemuck::try_cast_slice(entries)
+            .map_err(|_| error!(BettingError::IncorrectPeriod))?;
+        Ok((header, entries))
+    }
+
+    /// same as `from_data`, the discriminator is written first for a new account
+    pub fn from_data_mut(
+        data: &mut [u8],
+        is_new: bool,
+    ) -> Result<(&mut Self, &mut [LeaderboardEntry])> {
+        if is_new {
+            data[..8].copy_from_slice(&Self::discriminator());
+        }
+        require!(
+            data.len() >= 8 + Self::LEN && data[..8] == Self::discriminator(),
+            BettingError::IncorrectPeriod
+        );
+        let (header, entries) = data[8..].split_at_mut(Self::LEN);
+        let header: &mut Self = bytemuck::try_from_bytes_mut(header)
+            .map_err(|_| error!(BettingError::IncorrectPeriod))?;
+        let entries: &mut [LeaderboardEntry] = bytemuck::try_cast_slice_mut(entries)
+            .map_err(|_| error!(BettingError::IncorrectPeriod))?;
+        Ok((header, entries))
+    }
+}
+
+/// Set the cumulative `volume` of `user` in `entries[..count]`, sorted highest first.
+/// A new user only gets in when the board has room or they beat the last entry,
+/// earlier entries stay ahead on ties.
+pub fn record_volume(entries: &mut [LeaderboardEntry], count: &mut u64, user: Pubkey, volume: u64) {
+    let len = *count as usize;
+    let mut i = match entries[..len].iter().position(|entry| entry.user == user) {
+        Some(i) => i,
+        None if len < entries.len() => {
+            *count += 1;
+            len
+        }
+        None => {
+            let last = entries.len() - 1;
+            if entries[last].volume >= volume {
+                return;
+            }
+            last
+        }
+    };
+    entries[i] = LeaderboardEntry { user, volume };
+    // volumes only grow, so the entry can only move up
+    while i > 0 && entries[i - 1].volume < volume {
+        entries.swap(i - 1, i);
+        i -= 1;
+    }
+}
+
+/// true if `user` is an entry of the leaderboard account, false if the board was never created
+pub fn is_board_entry(leaderboard: &AccountInfo, user: &Pubkey) -> Result<bool> {
+    if leaderboard.data_is_empty() {
+        return Ok(false);
+    }
+    let data = leaderboard.try_borrow_data()?;
+    let (board, entries) = PeriodLeaderboard::from_data(&data)?;
+    Ok(entries[..board.count as usize]
+        .iter()
+        .any(|entry| entry.user == *user))
+}
+
+/// Volume thresholds of the tiers ending at the tiers' last ranks.
+/// Ranks past the end of the board take its lowest volume, an empty board has no winners.
+pub fn tier_thresholds(entries: &[LeaderboardEntry], tiers: &[PeriodTier]) -> [u64; MAX_PERIOD_TIERS] {
+    let mut thresholds = [u64::MAX; MAX_PERIOD_TIERS];
+    if let Some(lowest) = entries.last() {
+        for (threshold, tier) in thresholds.iter_mut().zip(tiers.iter()) {
+            *threshold = entries.get(tier.last_rank as usize - 1).unwrap_or(lowest).volume;
+        }
+    }
+    thresholds
+}
+
+/// FEEL needed to pay every leaderboard entry the reward of its tier,
+/// claim_rank_reward only pays entries of the board.
+pub fn tier_budget(entries: &[LeaderboardEntry], thresholds: &[u64], rewards: &[u64]) -> u64 {
+    entries
+        .iter()
+        .filter_map(|entry| thresholds.iter().position(|tier| entry.volume >= *tier))
+        .fold(0u64, |budget, position| budget.saturating_add(rewards[position]))
+}
+
+/// Number of leaderboard entries in each tier.
+pub fn tier_counts(entries: &[LeaderboardEntry], thresholds: &[u64]) -> [u64; MAX_PERIOD_TIERS] {
+    let mut counts = [0; MAX_PERIOD_TIERS];
+    for position in entries
+        .iter()
+        .filter_map(|entry| thresholds.iter().position(|tier| entry.volume >= *tier))
+    {
+        counts[position] += 1;
+    }
+    counts
+}
+
+/// Number of ranks in each tier.
+pub fn tier_ranks(tiers: &[PeriodTier]) -> [u64; MAX_PERIOD_TIERS] {
+    let mut ranks = [0; MAX_PERIOD_TIERS];
+    let mut first_rank = 1;
+    for (count, tier) in ranks.iter_mut().zip(tiers.iter()) {
+        *count = (tier.last_rank + 1 - first_rank) as u64;
+        first_rank = tier.last_rank + 1;
+    }
+    ranks
+}
+
+/// Tier of a 1-based `rank`, `tiers.len()` if it is below every tier.
+pub fn rank_tier(rank: u32, tiers: &[PeriodTier]) -> usize {
+    tiers
+        .iter()
+        .position(|tier| rank <= tier.last_rank as u32)
+        .unwrap_or(tiers.len())
+}
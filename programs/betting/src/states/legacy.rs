@@ -0,0 +1,271 @@
+use anchor_lang::{prelude::*, solana_program::hash::hash};
+
+use crate::{constants::*, error::*, states::*};
+
+use std::convert::TryInto;
+
+// Layouts written before accounts carried a version byte.
+// These accounts were sized with `8 + size_of::<T>()`,
+// which is how `migrate_account` recognizes them.
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct GlobalStateV0 {
+    pub is_initialized: u8,
+    pub authority: Pubkey,
+    pub treasury: Pubkey,
+
+    pub btc_pyth_account: Pubkey,
+    pub eth_pyth_account: Pubkey,
+    pub sol_pyth_account: Pubkey,
+    pub avax_pyth_account: Pubkey,
+    pub ada_pyth_account: Pubkey,
+
+    pub token_mint: Pubkey,
+    pub rank_mint: Pubkey,
+    pub arena_duration: u64,
+    pub platform_fee_rate: u64,
+    pub referral_fee_rate: u64,
+
+    pub reserves: [u64; 12],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct ArenaStateV0 {
+    pub arena_id: u64,
+    pub locked_price: u64,
+    pub start_timestamp: u64,
+    pub duration: u64,
+
+    pub status: u8,
+
+    pub up_amount: u64,
+    pub up_count: u64,
+    pub down_amount: u64,
+    pub down_count: u64,
+
+    pub final_price: u64,
+    pub end_timestamp: u64,
+    pub bet_result: u8,
+
+    pub reserves: [u64; 4],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct UserStateV0 {
+    pub user: Pubkey,
+    pub referrer: Pubkey,
+    pub is_ref_inited: u8,
+
+    pub ref_reward: u64,
+    pub reserves: [u64; 4],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct UserBetStateV0 {
+    pub user: Pubkey,
+
+    pub bet_timestamp: u64,
+    pub arena_id: u64,
+    pub bet_amount: u64,
+    pub is_up: u8,
+
+    pub is_claimed: u8,
+
+    pub reserves: [u64; 4],
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct NftBuildStateV0 {
+    pub user: Pubkey,
+    pub build_state: u16,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct EightBoxStateV0 {
+    pub user: Pubkey,
+    pub start_time: u64,
+    pub bet_amount: u64,
+    pub claimed_status: u8,
+}
+
+impl From<GlobalStateV0> for GlobalState {
+    fn from(old: GlobalStateV0) -> Self {
+        GlobalState {
+            version: GlobalState::VERSION,
+            is_initialized: old.is_initialized,
+            authority: old.authority,
+            treasury: old.treasury,
+            btc_pyth_account: old.btc_pyth_account,
+            eth_pyth_account: old.eth_pyth_account,
+            sol_pyth_account: old.sol_pyth_account,
+            avax_pyth_account: old.avax_pyth_account,
+            ada_pyth_account: old.ada_pyth_account,
+            token_mint: old.token_mint,
+            rank_mint: old.rank_mint,
+            arena_duration: old.arena_duration,
+            platform_fee_rate: old.platform_fee_rate,
+            referral_fee_rate: old.referral_fee_rate,
+            ..GlobalState::default()
+        }
+    }
+}
+
+impl From<ArenaStateV0> for ArenaState {
+    fn from(old: ArenaStateV0) -> Self {
+        ArenaState {
+            version: ArenaState::VERSION,
+            arena_id: old.arena_id,
+            locked_price: old.locked_price,
+            start_timestamp: old.start_timestamp,
+            duration: old.duration,
+            status: old.status,
+            up_amount: old.up_amount,
+            up_count: old.up_count,
+            down_amount: old.down_amount,
+            down_count: old.down_count,
+            final_price: old.final_price,
+            end_timestamp: old.end_timestamp,
+            bet_result: old.bet_result,
+            ..ArenaState::default()
+        }
+    }
+}
+
+impl From<UserStateV0> for UserState {
+    fn from(old: UserStateV0) -> Self {
+        UserState {
+            version: UserState::VERSION,
+            user: old.user,
+            referrer: old.referrer,
+            is_ref_inited: old.is_ref_inited,
+            ref_reward: old.ref_reward,
+        }
+    }
+}
+
+impl From<UserBetStateV0> for UserBetState {
+    fn from(old: UserBetStateV0) -> Self {
+        UserBetState {
+            version: UserBetState::VERSION,
+            user: old.user,
+            bet_timestamp: old.bet_timestamp,
+            arena_id: old.arena_id,
+            bet_amount: old.bet_amount,
+            is_up: old.is_up,
+            is_claimed: old.is_claimed,
+            ..Default::default()
+        }
+    }
+}
+
+impl From<NftBuildStateV0> for NftBuildState {
+    fn from(old: NftBuildStateV0) -> Self {
+        NftBuildState {
+            version: NftBuildState::VERSION,
+            user: old.user,
+            build_state: old.build_state,
+        }
+    }
+}
+
+impl From<EightBoxStateV0> for EightBoxState {
+    fn from(old: EightBoxStateV0) -> Self {
+        EightBoxState {
+            version: EightBoxState::VERSION,
+            user: old.user,
+            start_time: old.start_time,
+            bet_amount: old.bet_amount,
+            claimed_status: old.claimed_status as u64,
+        }
+    }
+}
+
+/// Hour, day and week rank accounts of the baseline program.
+/// They live at their own seeds instead of the period kind ones, so they are not migrated:
+/// claim_legacy_rank_reward pays and closes the states, close_legacy_result closes the results.
+pub struct LegacyPeriod {
+    pub state_seed: &'static [u8],
+    pub result_seed: &'static [u8],
+    pub state_account: &'static str,
+    pub result_account: &'static str,
+    pub tier_count: usize,
+    // (prize, bundle id) of the first tiers
+    pub prizes: &'static [(u8, usize)],
+}
+
+pub const LEGACY_PERIODS: [LegacyPeriod; 3] = [
+    LegacyPeriod {
+        state_seed: HOUR_STATE_SEED,
+        result_seed: HOUR_RESULT_SEED,
+        state_account: "HourState",
+        result_account: "HourResult",
+        tier_count: 5,
+        prizes: &[(RankPrize::Bundle as u8, 0)],
+    },
+    LegacyPeriod {
+        state_seed: DAY_STATE_SEED,
+        result_seed: DAY_RESULT_SEED,
+        state_account: "DayState",
+        result_account: "DayResult",
+        tier_count: 7,
+        prizes: &[(RankPrize::Bundle as u8, 4)],
+    },
+    LegacyPeriod {
+        state_seed: WEEK_STATE_SEED,
+        result_seed: WEEK_RESULT_SEED,
+        state_account: "WeekState",
+        result_account: "WeekResult",
+        tier_count: 9,
+        prizes: &[
+            (RankPrize::Nft as u8, 0),
+            (RankPrize::Bundle as u8, 4),
+            (RankPrize::Bundle as u8, 4),
+        ],
+    },
+];
+
+/// anchor discriminator of an account type by name
+pub fn legacy_discriminator(account: &str) -> [u8; 8] {
+    hash(format!("account:{}", account).as_bytes()).to_bytes()[..8]
+        .try_into()
+        .unwrap()
+}
+
+/// HourState, DayState and WeekState
+#[derive(AnchorSerialize, AnchorDeserialize, Default)]
+pub struct LegacyPeriodStateV0 {
+    pub user: Pubkey,
+    pub start_time: u64,
+    pub bet_amount: u64,
+    pub is_claimed: u8,
+}
+
+impl LegacyPeriod {
+    pub fn is_result(&self, data: &[u8]) -> bool {
+        data.len() >= 8 && data[..8] == legacy_discriminator(self.result_account)
+    }
+
+    pub fn state(&self, data: &[u8]) -> Result<LegacyPeriodStateV0> {
+        require!(
+            data.len() >= 8 && data[..8] == legacy_discriminator(self.state_account),
+            BettingError::UnsupportedAccountVersion
+        );
+        Ok(LegacyPeriodStateV0::deserialize(&mut &data[8..])?)
+    }
+
+    /// tier and FEEL reward of a volume in a result, none below the last tier
+    pub fn reward(&self, data: &[u8], volume: u64) -> Result<Option<(usize, u64)>> {
+        require!(
+            self.is_result(data) && data.len() >= 8 + 8 + 8 * 2 * self.tier_count,
+            BettingError::UnsupportedAccountVersion
+        );
+        let word = |i: usize| u64::from_le_bytes(data[16 + 8 * i..24 + 8 * i].try_into().unwrap());
+        Ok((0..self.tier_count)
+            .position(|i| volume >= word(i))
+            .map(|position| (position, word(self.tier_count + position))))
+    }
+
+    pub fn prize(&self, position: usize) -> Option<(u8, usize)> {
+        self.prizes.get(position).copied()
+    }
+}
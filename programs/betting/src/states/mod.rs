@@ -1,3 +1,8 @@
+pub mod versioned;
+pub use versioned::*;
+
+pub mod legacy;
+
 pub mod global_state;
 pub use global_state::*;
 
@@ -13,23 +18,38 @@ pub use user_state::*;
 pub mod eight_box_state;
 pub use eight_box_state::*;
 
-pub mod hour_state;
-pub use hour_state::*;
-
-pub mod day_state;
-pub use day_state::*;
-
-pub mod week_state;
-pub use week_state::*;
+pub mod eight_box_config;
+pub use eight_box_config::*;
 
 pub mod nft_build_state;
 pub use nft_build_state::*;
 
-pub mod hour_result;
-pub use hour_result::*;
+pub mod period_config;
+pub use period_config::*;
+
+pub mod period_state;
+pub use period_state::*;
+
+pub mod period_result;
+pub use period_result::*;
+
+pub mod leaderboard;
+pub use leaderboard::*;
+
+pub mod rank_claims;
+pub use rank_claims::*;
+
+pub mod vesting;
+pub use vesting::*;
+
+pub mod season;
+pub use season::*;
+
+pub mod season_state;
+pub use season_state::*;
 
-pub mod day_result;
-pub use day_result::*;
+pub mod fee_split;
+pub use fee_split::*;
 
-pub mod week_result;
-pub use week_result::*;
+pub mod bet_mint;
+pub use bet_mint::*;
@@ -1,8 +1,20 @@
 use anchor_lang::prelude::*;
 
+use crate::states::Versioned;
+
 #[account]
 #[derive(Default)]
 pub struct NftBuildState {
+    pub version: u8,
     pub user: Pubkey,
-    pub build_state: u16
+    pub build_state: u16,
+}
+
+impl Versioned for NftBuildState {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1 + 32 + 2;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
 }
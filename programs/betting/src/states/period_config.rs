@@ -0,0 +1,115 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, states::Versioned};
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PeriodTier {
+    // ranks after the previous tier's last_rank up to this one share the tier
+    pub last_rank: u16,
+    // RankPrize
+    pub prize: u8,
+    // bundle minted when prize is RankPrize::Bundle
+    pub bundle_id: u8,
+    // share of the period's prize pool split among the users of the tier, over FEE_RATE_DENOMINATOR
+    pub pool_share: u16,
+}
+
+impl PeriodTier {
+    pub const LEN: usize = 2 + 1 + 1 + 2;
+}
+
+/// A leaderboard cycle, period `n` of a kind runs from `n * length + epoch_offset` for `length` seconds.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default)]
+pub struct PeriodKind {
+    pub length: u64,
+    pub epoch_offset: u64,
+    pub is_enabled: u8,
+    // Ranking
+    pub ranking: u8,
+    pub tier_count: u8,
+    pub tiers: [PeriodTier; MAX_PERIOD_TIERS],
+    // FEEL rewards vest linearly over `vesting_duration` seconds from the claim,
+    // nothing is released before `vesting_cliff`. paid at once when the duration is 0
+    pub vesting_duration: u64,
+    pub vesting_cliff: u64,
+    // seconds results stay claimable after they are published
+    pub claim_window: u64,
+    // share of each arena fee paid into the prize pool of the current period, over FEE_RATE_DENOMINATOR
+    pub pool_fee_rate: u16,
+}
+
+impl PeriodKind {
+    pub const LEN: usize = 8 * 2 + 1 + 1 + 1 + PeriodTier::LEN * MAX_PERIOD_TIERS + 8 * 3 + 2;
+
+    pub fn is_profit_ranked(&self) -> bool {
+        self.ranking == Ranking::Profit as u8
+    }
+
+    pub fn tiers(&self) -> &[PeriodTier] {
+        &self.tiers[..self.tier_count as usize]
+    }
+
+    /// leaderboards keep the users of every tier
+    pub fn leaderboard_size(&self) -> usize {
+        self.tiers().last().map_or(0, |tier| tier.last_rank as usize)
+    }
+
+    pub fn period_index(&self, timestamp: u64) -> Result<u64> {
+        let elapsed = timestamp
+            .checked_sub(self.epoch_offset)
+            .ok_or(BettingError::IncorrectPeriod)?;
+        Ok(elapsed / self.length)
+    }
+
+    pub fn current_period(&self) -> Result<u64> {
+        self.period_index(Clock::get()?.unix_timestamp as u64)
+    }
+
+    pub fn start_time(&self, period: u64) -> u64 {
+        period * self.length + self.epoch_offset
+    }
+}
+
+/// Period kinds defined by the authority, a kind is identified by its index.
+#[account]
+#[derive(Default)]
+pub struct PeriodConfig {
+    pub version: u8,
+    pub kind_count: u8,
+    pub kinds: [PeriodKind; MAX_PERIOD_KINDS],
+}
+
+impl Versioned for PeriodConfig {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1 + 1 + PeriodKind::LEN * MAX_PERIOD_KINDS;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl PeriodConfig {
+    pub fn kind(&self, kind: u8) -> Result<&PeriodKind> {
+        require!(kind < self.kind_count, BettingError::UnknownPeriodKind);
+        Ok(&self.kinds[kind as usize])
+    }
+
+    /// enabled kinds with their ids, in the order user_bet expects their accounts
+    pub fn enabled_kinds(&self) -> impl Iterator<Item = (u8, &PeriodKind)> {
+        self.kinds[..self.kind_count as usize]
+            .iter()
+            .enumerate()
+            .filter(|(_, kind)| kind.is_enabled == 1)
+            .map(|(id, kind)| (id as u8, kind))
+    }
+
+    /// enabled kinds ranked by profit, in the order claim_reward and settle_loss expect their states
+    pub fn profit_kinds(&self) -> impl Iterator<Item = (u8, &PeriodKind)> {
+        self.enabled_kinds().filter(|(_, kind)| kind.is_profit_ranked())
+    }
+
+    /// enabled kinds funded from arena fees, in the order end_arena expects their pools
+    pub fn pool_kinds(&self) -> impl Iterator<Item = (u8, &PeriodKind)> {
+        self.enabled_kinds().filter(|(_, kind)| kind.pool_fee_rate > 0)
+    }
+}
@@ -0,0 +1,71 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    states::{PeriodTier, Versioned},
+};
+
+#[account]
+#[derive(Default)]
+pub struct PeriodResult {
+    pub version: u8,
+    pub kind: u8,
+    pub period: u64,
+    // Ranking of the kind
+    pub ranking: u8,
+    pub tier_count: u8,
+    // tier ranks and prizes of the kind when the result was published
+    pub tier_config: [PeriodTier; MAX_PERIOD_TIERS],
+    pub tiers: [u64; MAX_PERIOD_TIERS],
+    pub reward_per_tier: [u64; MAX_PERIOD_TIERS],
+    // all zero unless rewards are claimed with proofs against it
+    pub merkle_root: [u8; 32],
+    // FEEL locked in the result's reward vault at publication, and paid out of it so far
+    pub budget: u64,
+    pub claimed: u64,
+    // vesting schedule of the kind when the result was published
+    pub vesting_duration: u64,
+    pub vesting_cliff: u64,
+    // claims are rejected after it, the result can only be closed after it
+    pub claim_deadline: u64,
+    // bet tokens of the period's fee funded prize pool, and the pool payout of each user of a tier
+    pub pool_budget: u64,
+    pub pool_claimed: u64,
+    pub pool_per_tier: [u64; MAX_PERIOD_TIERS],
+}
+
+impl Versioned for PeriodResult {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1 + 1 + 8 + 1 + 1 + (PeriodTier::LEN + 8 * 3) * MAX_PERIOD_TIERS + 32 + 8 * 7;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl PeriodResult {
+    pub fn tier_config(&self) -> &[PeriodTier] {
+        &self.tier_config[..self.tier_count as usize]
+    }
+
+    pub fn tiers(&self) -> &[u64] {
+        &self.tiers[..self.tier_count as usize]
+    }
+
+    pub fn is_claimable(&self, timestamp: u64) -> bool {
+        timestamp <= self.claim_deadline
+    }
+
+    /// pool payout of a tier, nothing for ranks below the last tier
+    pub fn pool_reward(&self, position: usize) -> u64 {
+        self.pool_per_tier[..self.tier_count as usize]
+            .get(position)
+            .copied()
+            .unwrap_or(0)
+    }
+
+    /// tier of a volume, none if it is below the last tier
+    pub fn volume_tier(&self, volume: u64) -> Option<usize> {
+        self.tiers().iter().position(|tier| volume >= *tier)
+    }
+}
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::*,
+    states::{PeriodConfig, Versioned},
+    utils::is_current_layout,
+};
+
+use std::slice::Iter;
+
+/// Volume and realised PnL of a user in one period of a kind.
+#[account]
+#[derive(Default)]
+pub struct PeriodState {
+    pub version: u8,
+    pub kind: u8,
+    pub period: u64,
+    pub user: Pubkey,
+    pub start_time: u64,
+    pub bet_amount: u64,
+    pub is_claimed: u8,
+    // settled winnings minus lost stakes of the bets placed in the period
+    pub pnl: i64,
+}
+
+impl Versioned for PeriodState {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1 + 1 + 8 + 32 + 8 * 2 + 1 + 8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+/// Add the `pnl` of a settled bet to the user's states of the periods it was placed in,
+/// one account per profit ranked kind.
+/// A state that was never created or is already closed is skipped.
+pub fn record_pnl<'info>(
+    config: &PeriodConfig,
+    rem_accts: &mut Iter<AccountInfo<'info>>,
+    program_id: &Pubkey,
+    user: &Pubkey,
+    bet_timestamp: u64,
+    pnl: i64,
+) -> Result<()> {
+    for (kind, period_kind) in config.profit_kinds() {
+        let state_info = next_account_info(rem_accts)?;
+        let period = period_kind.period_index(bet_timestamp)?;
+        let (key, _) = Pubkey::find_program_address(
+            &[PERIOD_STATE_SEED, &[kind], user.as_ref(), &period.to_le_bytes()],
+            program_id,
+        );
+        require_keys_eq!(state_info.key(), key, BettingError::IncorrectPeriod);
+        if state_info.data_is_empty() {
+            continue;
+        }
+        let mut state: Account<PeriodState> = Account::try_from(state_info)?;
+        require!(
+            is_current_layout(&state),
+            BettingError::UnsupportedAccountVersion
+        );
+        state.pnl = state.pnl.checked_add(pnl).unwrap();
+        state.exit(program_id)?;
+    }
+    Ok(())
+}
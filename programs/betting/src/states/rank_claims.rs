@@ -0,0 +1,94 @@
+use anchor_lang::{prelude::*, solana_program::keccak};
+
+use crate::{error::*, states::Versioned};
+
+/// Root of the (index, user, rank, reward) leaves ranked off-chain for one period.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy)]
+pub struct MerkleRoot {
+    pub root: [u8; 32],
+    pub leaf_count: u32,
+    // sum of the leaf rewards, locked as the period budget
+    pub total_reward: u64,
+}
+
+/// A user's leaf in the published tree, `proof` runs from the leaf up to the root.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone)]
+pub struct RankProof {
+    pub index: u32,
+    pub rank: u32,
+    pub reward: u64,
+    pub proof: Vec<[u8; 32]>,
+}
+
+/// Claimed bitmap of the leaves of one period result, empty if the result has no root.
+#[account]
+#[derive(Default)]
+pub struct RankClaims {
+    pub version: u8,
+    pub leaf_count: u32,
+    pub claimed: Vec<u8>,
+}
+
+impl Versioned for RankClaims {
+    const VERSION: u8 = 1;
+    // without the bitmap, see `space`
+    const LEN: usize = 1 + 4 + 4;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl RankClaims {
+    pub fn space(leaf_count: u32) -> usize {
+        8 + Self::LEN + (leaf_count as usize + 7) / 8
+    }
+
+    pub fn init(&mut self, leaf_count: u32) {
+        self.version = Self::VERSION;
+        self.leaf_count = leaf_count;
+        self.claimed = vec![0; (leaf_count as usize + 7) / 8];
+    }
+
+    pub fn is_claimed(&self, index: u32) -> bool {
+        self.claimed[index as usize / 8] & (1 << (index % 8)) != 0
+    }
+
+    pub fn set_claimed(&mut self, index: u32) {
+        self.claimed[index as usize / 8] |= 1 << (index % 8);
+    }
+
+    /// check that `proof` is an unclaimed leaf of `user` under `root`
+    pub fn verify(&self, root: &[u8; 32], user: &Pubkey, proof: &RankProof) -> Result<()> {
+        require!(*root != [0; 32], BettingError::InvalidMerkleProof);
+        require!(proof.index < self.leaf_count, BettingError::InvalidMerkleProof);
+        require!(!self.is_claimed(proof.index), BettingError::AlreadyClaimed);
+        require!(
+            verify_proof(&proof.proof, root, rank_leaf(proof.index, user, proof.rank, proof.reward)),
+            BettingError::InvalidMerkleProof
+        );
+        Ok(())
+    }
+}
+
+pub fn rank_leaf(index: u32, user: &Pubkey, rank: u32, reward: u64) -> [u8; 32] {
+    keccak::hashv(&[
+        &index.to_le_bytes(),
+        user.as_ref(),
+        &rank.to_le_bytes(),
+        &reward.to_le_bytes(),
+    ])
+    .0
+}
+
+/// nodes hash their children in sorted order, so proofs carry no left/right flags
+pub fn verify_proof(proof: &[[u8; 32]], root: &[u8; 32], leaf: [u8; 32]) -> bool {
+    let computed = proof.iter().fold(leaf, |node, sibling| {
+        if node <= *sibling {
+            keccak::hashv(&[&node, sibling]).0
+        } else {
+            keccak::hashv(&[sibling, &node]).0
+        }
+    });
+    computed == *root
+}
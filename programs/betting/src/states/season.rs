@@ -0,0 +1,112 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    constants::*,
+    error::*,
+    states::{record_volume, PeriodLeaderboard, Versioned},
+};
+
+/// A multi-week competition ranked by volume plus `win_bonus` per won bet.
+/// The top finishers claim a trophy NFT and FEEL once the ranker ends it.
+#[account]
+#[derive(Default)]
+pub struct Season {
+    pub version: u8,
+    pub season_id: u64,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub win_bonus: u64,
+    pub is_ended: u8,
+    pub winner_count: u8,
+    // bit i set once winners[i] claimed
+    pub claimed: u16,
+    pub winners: [Pubkey; MAX_SEASON_TROPHIES],
+    pub rewards: [u64; MAX_SEASON_TROPHIES],
+    // trophy metadata
+    pub name: String,
+    pub uri: String,
+    // set by create_season, the deadline is stamped by end_season
+    pub claim_window: u64,
+    pub claim_deadline: u64,
+    // FEEL locked in the season's reward vault by end_season and paid out of it
+    pub budget: u64,
+    pub reward_claimed: u64,
+}
+
+impl Versioned for Season {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1
+        + 8 * 4
+        + 1
+        + 1
+        + 2
+        + (32 + 8) * MAX_SEASON_TROPHIES
+        + 4
+        + MAX_SEASON_NAME_LEN
+        + 4
+        + MAX_SEASON_URI_LEN
+        + 8 * 4;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl Season {
+    pub fn is_running(&self, timestamp: u64) -> bool {
+        self.is_ended == 0 && self.start_time <= timestamp && timestamp < self.end_time
+    }
+
+    /// 0-based rank of a trophy winner
+    pub fn winner_rank(&self, user: &Pubkey) -> Option<usize> {
+        self.winners[..self.winner_count as usize]
+            .iter()
+            .position(|winner| winner == user)
+    }
+
+    pub fn is_claimed(&self, rank: usize) -> bool {
+        self.claimed & (1 << rank) != 0
+    }
+
+    pub fn is_claimable(&self, timestamp: u64) -> bool {
+        timestamp <= self.claim_deadline
+    }
+
+    /// book a trophy's FEEL reward against the locked budget
+    pub fn book_reward(&mut self, reward: u64) -> Result<()> {
+        self.reward_claimed = self
+            .reward_claimed
+            .checked_add(reward)
+            .filter(|claimed| *claimed <= self.budget)
+            .ok_or(BettingError::RewardBudgetExceeded)?;
+        Ok(())
+    }
+
+    pub fn trophy_name(&self, rank: usize) -> String {
+        format!("{} #{}", self.name, rank + 1)
+    }
+
+    /// Set the score of `user` on the season leaderboard.
+    /// Only create_season writes a leaderboard header with this kind and season id.
+    pub fn record_score(
+        &self,
+        board_info: &AccountInfo,
+        program_id: &Pubkey,
+        user: Pubkey,
+        score: u64,
+    ) -> Result<()> {
+        require_keys_eq!(*board_info.owner, *program_id, BettingError::IncorrectPeriod);
+        let mut data = board_info.try_borrow_mut_data()?;
+        let (board, entries) = PeriodLeaderboard::from_data_mut(&mut data, false)?;
+        require!(
+            board.version == PeriodLeaderboard::VERSION,
+            BettingError::UnsupportedAccountVersion
+        );
+        require!(
+            board.kind == SEASON_LEADERBOARD_KIND && board.period == self.season_id,
+            BettingError::IncorrectPeriod
+        );
+        record_volume(entries, &mut board.count, user, score);
+        Ok(())
+    }
+}
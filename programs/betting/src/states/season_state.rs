@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::states::Versioned;
+
+/// Volume and won bets of a user in one season.
+#[account]
+#[derive(Default)]
+pub struct SeasonState {
+    pub version: u8,
+    pub season_id: u64,
+    pub user: Pubkey,
+    pub bet_amount: u64,
+    pub wins: u64,
+}
+
+impl Versioned for SeasonState {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1 + 8 + 32 + 8 * 2;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl SeasonState {
+    /// leaderboard score, each won bet counts as `win_bonus` of volume
+    pub fn score(&self, win_bonus: u64) -> u64 {
+        self.wins
+            .saturating_mul(win_bonus)
+            .saturating_add(self.bet_amount)
+    }
+}
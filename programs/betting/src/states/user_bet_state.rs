@@ -1,8 +1,11 @@
 use anchor_lang::prelude::*;
 
+use crate::states::Versioned;
+
 #[account]
 #[derive(Default)]
 pub struct UserBetState {
+    pub version: u8,
     pub user: Pubkey,
 
     pub bet_timestamp: u64,
@@ -12,5 +15,20 @@ pub struct UserBetState {
 
     pub is_claimed: u8,
 
-    pub reserves: [u64; 4],
+    // set once a winning bet's share is booked, by settle_win or claim_reward
+    pub is_settled: u8,
+    pub reward: u64,
+    pub referral_fee: u64,
+
+    // stake added to the BetMint's open_stakes, only bets on booked arenas are
+    pub is_booked: u8,
+}
+
+impl Versioned for UserBetState {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1 + 32 + 8 * 3 + 1 + 1 + 1 + 8 * 2 + 1;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
 }
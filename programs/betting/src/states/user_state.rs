@@ -1,12 +1,23 @@
 use anchor_lang::prelude::*;
 
+use crate::states::Versioned;
+
 #[account]
 #[derive(Default)]
 pub struct UserState {
+    pub version: u8,
     pub user: Pubkey,
     pub referrer: Pubkey,
     pub is_ref_inited: u8,
 
     pub ref_reward: u64,
-    pub reserves: [u64; 4],
+}
+
+impl Versioned for UserState {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1 + 32 * 2 + 1 + 8;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
 }
@@ -0,0 +1,9 @@
+/// Every state account starts with a layout version byte.
+/// `VERSION` is bumped whenever the layout changes,
+/// `LEN` is the serialized size of the current layout without the discriminator.
+pub trait Versioned {
+    const VERSION: u8;
+    const LEN: usize;
+
+    fn version(&self) -> u8;
+}
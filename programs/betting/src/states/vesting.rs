@@ -0,0 +1,44 @@
+use anchor_lang::prelude::*;
+
+use crate::states::Versioned;
+
+/// FEEL of a rank reward claim released linearly to its user.
+/// The tokens sit in the ATA of this account until withdrawn.
+#[account]
+#[derive(Default)]
+pub struct Vesting {
+    pub version: u8,
+    pub user: Pubkey,
+    pub period_result: Pubkey,
+    pub start_time: u64,
+    pub cliff_time: u64,
+    pub end_time: u64,
+    pub total: u64,
+    pub withdrawn: u64,
+}
+
+impl Versioned for Vesting {
+    const VERSION: u8 = 1;
+    const LEN: usize = 1 + 32 * 2 + 8 * 5;
+
+    fn version(&self) -> u8 {
+        self.version
+    }
+}
+
+impl Vesting {
+    pub fn vested(&self, timestamp: u64) -> u64 {
+        if timestamp < self.cliff_time {
+            return 0;
+        }
+        if timestamp >= self.end_time {
+            return self.total;
+        }
+        (self.total as u128 * (timestamp - self.start_time) as u128
+            / (self.end_time - self.start_time) as u128) as u64
+    }
+
+    pub fn withdrawable(&self, timestamp: u64) -> u64 {
+        self.vested(timestamp).saturating_sub(self.withdrawn)
+    }
+}
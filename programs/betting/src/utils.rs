@@ -1,12 +1,23 @@
-use crate::{constants::*, error::*};
+use crate::{constants::*, error::*, states::Versioned};
 use anchor_lang::{
     prelude::*,
-    solana_program::{hash::hash, program::invoke_signed, pubkey},
+    solana_program::{
+        hash::hash,
+        instruction::{AccountMeta, Instruction},
+        program::{invoke, invoke_signed},
+        program_pack::{IsInitialized, Pack},
+        pubkey, sysvar,
+    },
+    system_program::{self, Allocate, Assign},
 };
 use anchor_spl::{
     associated_token::{self, AssociatedToken, Create},
-    token::{self, Mint, MintTo, Token, TokenAccount, Transfer},
+    token::{
+        self, spl_token, CloseAccount, InitializeAccount, Mint, MintTo, Token, TokenAccount,
+        Transfer,
+    },
 };
+use std::convert::TryInto;
 use mpl_token_metadata::{
     instruction::{ create_metadata_accounts_v2, create_master_edition_v3 }, 
     state::{ 
@@ -17,6 +28,15 @@ use mpl_token_metadata::{
     ID as MetadataProgramID,
 };
 
+/// true if the account was written with the layout this program understands
+pub fn is_current_layout<'info, T>(account: &Account<'info, T>) -> bool
+where
+    T: Versioned + AccountSerialize + AccountDeserialize + Owner + Clone,
+{
+    account.version() == T::VERSION
+        && AsRef::<AccountInfo>::as_ref(account).data_len() == 8 + T::LEN
+}
+
 pub fn assert_ref_hash(
     user_pk: Pubkey,
     ref_key: Pubkey,
@@ -32,6 +52,436 @@ pub fn assert_ref_hash(
     Ok(())
 }
 
+/// fails if escrow_ata holds less than the liabilities of its mint after a transfer
+pub fn assert_solvent(escrow_ata: &AccountInfo, liabilities: u64) -> Result<()> {
+    require!(
+        token_amount(escrow_ata)? >= liabilities,
+        BettingError::EscrowInsolvent
+    );
+    Ok(())
+}
+
+/// true for the token program and Token-2022, their accounts share the base layouts
+pub fn is_token_program(key: &Pubkey) -> bool {
+    *key == token::ID || *key == TOKEN_2022_PROGRAM_ID
+}
+
+/// associated token address of `wallet` for `mint` under either token program
+pub fn associated_token_address(wallet: &Pubkey, mint: &Pubkey, token_program: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &[wallet.as_ref(), token_program.as_ref(), mint.as_ref()],
+        &associated_token::ID,
+    )
+    .0
+}
+
+/// amount held by a token account of either token program, extensions are skipped
+pub fn token_amount(account: &AccountInfo) -> Result<u64> {
+    require!(
+        is_token_program(account.owner),
+        BettingError::IncorrectTokenAccount
+    );
+    let data = account.try_borrow_data()?;
+    require!(
+        data.len() >= spl_token::state::Account::LEN,
+        BettingError::IncorrectTokenAccount
+    );
+    let state = spl_token::state::Account::unpack_from_slice(&data[..spl_token::state::Account::LEN])?;
+    require!(state.is_initialized(), BettingError::IncorrectTokenAccount);
+    Ok(state.amount)
+}
+
+/// decimals of a mint of either token program
+pub fn mint_decimals(mint: &AccountInfo) -> Result<u8> {
+    require!(is_token_program(mint.owner), BettingError::IncorrectMint);
+    let data = mint.try_borrow_data()?;
+    require!(
+        data.len() >= spl_token::state::Mint::LEN,
+        BettingError::IncorrectMint
+    );
+    let state = spl_token::state::Mint::unpack_from_slice(&data[..spl_token::state::Mint::LEN])?;
+    require!(state.is_initialized(), BettingError::IncorrectMint);
+    Ok(state.decimals)
+}
+
+/// Token-2022 transfer fee withheld when `amount` of `mint` is moved, 0 without the extension
+pub fn transfer_fee(mint: &AccountInfo, amount: u64) -> Result<u64> {
+    if *mint.owner != TOKEN_2022_PROGRAM_ID {
+        return Ok(0);
+    }
+    let data = mint.try_borrow_data()?;
+    // extensions follow the base mint padded to the account size and an account type byte
+    let mut offset = spl_token::state::Account::LEN + 1;
+    while offset + 4 <= data.len() {
+        let ext_type = u16::from_le_bytes([data[offset], data[offset + 1]]);
+        let ext_len = u16::from_le_bytes([data[offset + 2], data[offset + 3]]) as usize;
+        let value = offset + 4;
+        if ext_type == TRANSFER_FEE_CONFIG_EXTENSION {
+            // two authorities and the withheld amount, then the older and newer fees
+            // of 18 bytes each: epoch, maximum_fee, basis points
+            require!(
+                ext_len == 108 && value + ext_len <= data.len(),
+                BettingError::IncorrectMint
+            );
+            let read_u64 = |at: usize| u64::from_le_bytes(data[at..at + 8].try_into().unwrap());
+            let newer = value + 90;
+            let fee = if Clock::get()?.epoch >= read_u64(newer) {
+                newer
+            } else {
+                value + 72
+            };
+            let maximum_fee = read_u64(fee + 8);
+            let basis_points = u16::from_le_bytes([data[fee + 16], data[fee + 17]]) as u128;
+            // rounded up like the token program does
+            let raw = (amount as u128 * basis_points + FEE_RATE_DENOMINATOR as u128 - 1)
+                / FEE_RATE_DENOMINATOR as u128;
+            return Ok((raw as u64).min(maximum_fee));
+        }
+        offset = value + ext_len;
+    }
+    Ok(0)
+}
+
+/// `transfer_checked` through either token program, returns the amount `to` receives
+pub fn transfer_checked<'info>(
+    token_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    to: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+    amount: u64,
+) -> Result<u64> {
+    let ix = Instruction {
+        program_id: token_program.key(),
+        accounts: vec![
+            AccountMeta::new(from.key(), false),
+            AccountMeta::new_readonly(mint.key(), false),
+            AccountMeta::new(to.key(), false),
+            AccountMeta::new_readonly(authority.key(), true),
+        ],
+        data: spl_token::instruction::TokenInstruction::TransferChecked {
+            amount,
+            decimals: mint_decimals(&mint)?,
+        }
+        .pack(),
+    };
+    let fee = transfer_fee(&mint, amount)?;
+    invoke_signed(&ix, &[from, mint, to, authority, token_program], signer_seeds)?;
+    Ok(amount - fee)
+}
+
+/// `burn_checked` through either token program
+pub fn burn_checked<'info>(
+    token_program: AccountInfo<'info>,
+    from: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: token_program.key(),
+        accounts: vec![
+            AccountMeta::new(from.key(), false),
+            AccountMeta::new(mint.key(), false),
+            AccountMeta::new_readonly(authority.key(), true),
+        ],
+        data: spl_token::instruction::TokenInstruction::BurnChecked {
+            amount,
+            decimals: mint_decimals(&mint)?,
+        }
+        .pack(),
+    };
+    invoke(&ix, &[from, mint, authority, token_program])?;
+    Ok(())
+}
+
+/// `close_account` through either token program, the account must be empty
+pub fn close_token_account<'info>(
+    token_program: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    destination: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    signer_seeds: &[&[&[u8]]],
+) -> Result<()> {
+    let ix = Instruction {
+        program_id: token_program.key(),
+        accounts: vec![
+            AccountMeta::new(account.key(), false),
+            AccountMeta::new(destination.key(), false),
+            AccountMeta::new_readonly(authority.key(), true),
+        ],
+        data: spl_token::instruction::TokenInstruction::CloseAccount.pack(),
+    };
+    invoke_signed(&ix, &[account, destination, authority, token_program], signer_seeds)?;
+    Ok(())
+}
+
+/// create the ATA of `wallet` for `mint` under either token program if it doesn't exist yet
+pub fn create_ata_if_needed<'info>(
+    payer: AccountInfo<'info>,
+    ata: AccountInfo<'info>,
+    wallet: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    rent: AccountInfo<'info>,
+) -> Result<()> {
+    require_keys_eq!(
+        ata.key(),
+        associated_token_address(wallet.key, mint.key, token_program.key),
+        BettingError::IncorrectTokenAccount
+    );
+    if !ata.data_is_empty() {
+        return Ok(());
+    }
+    // the token program is passed explicitly, anchor_spl's create always uses the classic one
+    let ix = Instruction {
+        program_id: associated_token::ID,
+        accounts: vec![
+            AccountMeta::new(payer.key(), true),
+            AccountMeta::new(ata.key(), false),
+            AccountMeta::new_readonly(wallet.key(), false),
+            AccountMeta::new_readonly(mint.key(), false),
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new_readonly(token_program.key(), false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+        ],
+        data: vec![],
+    };
+    invoke(
+        &ix,
+        &[payer, ata, wallet, mint, system_program, token_program, rent],
+    )?;
+    Ok(())
+}
+
+/// true for the wrapped SOL mint, arenas in it take and pay lamports
+pub fn is_native_mint(mint: &Pubkey) -> bool {
+    *mint == spl_token::native_mint::ID
+}
+
+/// Value of `lamports` in units of a mint with `decimals` at the SOL price of `sol_pyth_account`,
+/// SOL stakes count towards volumes in the default bet mint this way
+pub fn sol_to_volume(lamports: u64, sol_pyth_account: &AccountInfo, decimals: u8) -> Result<u64> {
+    let pyth_price_data = &sol_pyth_account.try_borrow_data()?;
+    let pyth_price = pyth_client::cast::<pyth_client::Price>(pyth_price_data);
+    require!(pyth_price.agg.price > 0, BettingError::InvalidPrice);
+
+    let value = (lamports as u128)
+        .checked_mul(pyth_price.agg.price as u128)
+        .unwrap();
+    let exponent = pyth_price.expo + decimals as i32 - spl_token::native_mint::DECIMALS as i32;
+    let value = if exponent >= 0 {
+        value.checked_mul(10u128.pow(exponent as u32)).unwrap()
+    } else {
+        value.checked_div(10u128.pow(exponent.unsigned_abs())).unwrap()
+    };
+    Ok(value.try_into().unwrap())
+}
+
+/// move `amount` lamports of `user` into the wSOL `escrow_ata`
+pub fn wrap_sol<'info>(
+    user: AccountInfo<'info>,
+    escrow_ata: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    amount: u64,
+) -> Result<()> {
+    system_program::transfer(
+        CpiContext::new(
+            system_program,
+            system_program::Transfer {
+                from: user,
+                to: escrow_ata.clone(),
+            },
+        ),
+        amount,
+    )?;
+    invoke(
+        &spl_token::instruction::sync_native(&spl_token::ID, escrow_ata.key)?,
+        &[escrow_ata],
+    )?;
+    Ok(())
+}
+
+/// Pay `amount` of `mint` held by `from` to `user`, returns what the user receives.
+/// In SOL arenas the wSOL goes through `unwrap_account`, a token account created
+/// and closed to `user` here, so the user gets lamports. Otherwise `user_ata`
+/// must be the user's ATA of `mint` and Token-2022 transfer fees are withheld.
+pub fn pay_out<'info>(
+    from: AccountInfo<'info>,
+    authority: AccountInfo<'info>,
+    authority_seeds: &[&[u8]],
+    user: AccountInfo<'info>,
+    user_ata: AccountInfo<'info>,
+    unwrap_account: AccountInfo<'info>,
+    mint: AccountInfo<'info>,
+    token_program: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    rent: AccountInfo<'info>,
+    program_id: &Pubkey,
+    amount: u64,
+) -> Result<u64> {
+    if !is_native_mint(mint.key) {
+        require_keys_eq!(
+            user_ata.key(),
+            associated_token_address(user.key, mint.key, token_program.key),
+            BettingError::IncorrectTokenAccount
+        );
+        return transfer_checked(
+            token_program,
+            from,
+            mint,
+            user_ata,
+            authority,
+            &[authority_seeds],
+            amount,
+        );
+    }
+
+    let (unwrap_key, unwrap_bump) =
+        Pubkey::find_program_address(&[UNWRAP_SEED, user.key.as_ref()], program_id);
+    require_keys_eq!(
+        unwrap_account.key(),
+        unwrap_key,
+        BettingError::IncorrectTokenAccount
+    );
+    let unwrap_seeds: &[&[u8]] = &[UNWRAP_SEED, user.key.as_ref(), &[unwrap_bump]];
+
+    create_pda_account(
+        user.clone(),
+        unwrap_account.clone(),
+        system_program,
+        spl_token::state::Account::LEN,
+        &token::ID,
+        unwrap_seeds,
+    )?;
+
+    token::initialize_account(CpiContext::new(
+        token_program.clone(),
+        InitializeAccount {
+            account: unwrap_account.clone(),
+            mint,
+            authority: authority.clone(),
+            rent,
+        },
+    ))?;
+    token::transfer(
+        CpiContext::new(
+            token_program.clone(),
+            Transfer {
+                from,
+                to: unwrap_account.clone(),
+                authority: authority.clone(),
+            },
+        )
+        .with_signer(&[authority_seeds]),
+        amount,
+    )?;
+    // closing sends the rent and the unwrapped amount to the user
+    token::close_account(
+        CpiContext::new(
+            token_program,
+            CloseAccount {
+                account: unwrap_account,
+                destination: user,
+                authority,
+            },
+        )
+        .with_signer(&[authority_seeds]),
+    )?;
+    Ok(amount)
+}
+
+/// Create the PDA `account` with `space` bytes owned by `owner`, `payer` funds the rent.
+/// Same steps as anchor init, so lamports sent to the address beforehand don't block it.
+pub fn create_pda_account<'info>(
+    payer: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    space: usize,
+    owner: &Pubkey,
+    seeds: &[&[u8]],
+) -> Result<()> {
+    let lamports = Rent::get()?
+        .minimum_balance(space)
+        .saturating_sub(account.lamports());
+    if lamports > 0 {
+        system_program::transfer(
+            CpiContext::new(
+                system_program.clone(),
+                system_program::Transfer {
+                    from: payer,
+                    to: account.clone(),
+                },
+            ),
+            lamports,
+        )?;
+    }
+    system_program::allocate(
+        CpiContext::new(
+            system_program.clone(),
+            Allocate {
+                account_to_allocate: account.clone(),
+            },
+        )
+        .with_signer(&[seeds]),
+        space as u64,
+    )?;
+    system_program::assign(
+        CpiContext::new(
+            system_program,
+            Assign {
+                account_to_assign: account,
+            },
+        )
+        .with_signer(&[seeds]),
+        owner,
+    )?;
+    Ok(())
+}
+
+/// Create the program owned PDA of `seeds` at `account` unless it exists, true if it was created.
+pub fn init_pda_if_needed<'info>(
+    payer: AccountInfo<'info>,
+    account: AccountInfo<'info>,
+    system_program: AccountInfo<'info>,
+    space: usize,
+    seeds: &[&[u8]],
+    program_id: &Pubkey,
+) -> Result<bool> {
+    if !account.data_is_empty() {
+        require_keys_eq!(*account.owner, *program_id, BettingError::IncorrectPeriod);
+        return Ok(false);
+    }
+    let (key, bump) = Pubkey::find_program_address(seeds, program_id);
+    require_keys_eq!(account.key(), key, BettingError::IncorrectPeriod);
+    let bump = [bump];
+    let signer_seeds = [seeds, &[&bump[..]]].concat();
+    create_pda_account(payer, account, system_program, space, program_id, &signer_seeds)?;
+    Ok(true)
+}
+
+/// Close a program owned account without deserializing it, the way anchor's `close` does,
+/// for accounts whose layout this program no longer reads.
+pub fn close_program_account<'info>(
+    account: &AccountInfo<'info>,
+    destination: &AccountInfo<'info>,
+) -> Result<()> {
+    let lamports = account.lamports();
+    **destination.try_borrow_mut_lamports()? = destination.lamports().checked_add(lamports).unwrap();
+    **account.try_borrow_mut_lamports()? = 0;
+    account.try_borrow_mut_data()?[..8]
+        .copy_from_slice(&anchor_lang::__private::CLOSED_ACCOUNT_DISCRIMINATOR);
+    Ok(())
+}
+
+/// index of the current hour, day, week or 8 hour window since the unix epoch
+pub fn current_period_index(period: u64) -> Result<u64> {
+    let current_time = Clock::get()?.unix_timestamp as u64;
+    Ok(current_time.checked_div(period).unwrap())
+}
+
 pub fn fragment_seed(
     fragment_id: u8
 ) -> String {
@@ -101,6 +551,8 @@ pub fn mint_nft<'a>(
     rent: AccountInfo<'a>,
     treasury_key: Pubkey,
     program_id: &Pubkey,
+    name: String,
+    uri: String,
 ) -> Result<()> {
     let (_, bump) = Pubkey::find_program_address(&[NFT_MINTER_SEED.as_ref()], program_id);
     let signer_seeds = &[NFT_MINTER_SEED, &[bump]];
@@ -149,9 +601,9 @@ pub fn mint_nft<'a>(
             collection_minter.key(),
             user.key(),
             collection_minter.key(),
-            NFT_NAME.to_string(),
+            name,
             NFT_SYMBOL.to_string(),
-            NFT_URI.to_string(),
+            uri,
             Some(creators),
             1000u16,
             false,
@@ -0,0 +1,120 @@
+use anchor_lang::prelude::*;
+
+use crate::{constants::*, error::*, states::*, utils::*};
+
+/// Standing of a user in a published period result.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Default)]
+pub struct RankView {
+    pub is_ranked: bool,
+    // index in the result's tiers, meaningless unless ranked
+    pub tier: u8,
+    // FEEL owed for the tier or the proven leaf
+    pub reward: u64,
+    // RankPrize of the tier, with the bundle minted for RankPrize::Bundle
+    pub prize: u8,
+    pub bundle_id: Option<u8>,
+    // bet tokens owed from the prize pool
+    pub pool_reward: u64,
+    pub is_claimed: bool,
+    pub claim_deadline: u64,
+}
+
+#[derive(Accounts)]
+pub struct GetRank<'info> {
+    /// CHECK: only used to derive the period state
+    pub user: AccountInfo<'info>,
+    /// CHECK: user's PeriodState, parsed in handler, unranked if it was never created or is closed
+    #[account(
+        seeds = [PERIOD_STATE_SEED, &[period_result.kind], user.key().as_ref(), &period_result.period.to_le_bytes()],
+        bump
+    )]
+    pub user_period_state: AccountInfo<'info>,
+    #[account(
+        seeds = [PERIOD_RESULT_SEED, &[period_result.kind], &period_result.period.to_le_bytes()],
+        bump,
+        constraint = is_current_layout(&period_result) @ BettingError::UnsupportedAccountVersion
+    )]
+    pub period_result: Box<Account<'info, PeriodResult>>,
+    /// CHECK: zero copy leaderboard of the period, results without a root only rank its entries
+    #[account(
+        seeds = [PERIOD_LEADERBOARD_SEED, &[period_result.kind], &period_result.period.to_le_bytes()],
+        bump
+    )]
+    pub period_leaderboard: AccountInfo<'info>,
+    #[account(seeds = [RANK_CLAIMS_SEED, period_result.key().as_ref()], bump)]
+    pub rank_claims: Box<Account<'info, RankClaims>>,
+}
+
+impl<'info> GetRank<'info> {
+    /// tier, reward and claim state of the user,
+    /// a result published as a Merkle root needs the user's proof
+    fn standing(
+        &self,
+        state: &PeriodState,
+        proof: &Option<RankProof>,
+    ) -> Result<Option<(usize, u64, bool)>> {
+        let result = &self.period_result;
+        if result.ranking == Ranking::Profit as u8 && state.pnl <= 0 {
+            return Ok(None);
+        }
+        if result.merkle_root == [0; 32] {
+            if !is_board_entry(&self.period_leaderboard, &state.user)? {
+                return Ok(None);
+            }
+            return Ok(result.volume_tier(state.bet_amount).map(|position| {
+                (position, result.reward_per_tier[position], state.is_claimed == 1)
+            }));
+        }
+        Ok(proof
+            .as_ref()
+            .filter(|proof| {
+                proof.index < self.rank_claims.leaf_count
+                    && verify_proof(
+                        &proof.proof,
+                        &result.merkle_root,
+                        rank_leaf(proof.index, &state.user, proof.rank, proof.reward),
+                    )
+            })
+            .map(|proof| {
+                (
+                    rank_tier(proof.rank, result.tier_config()),
+                    proof.reward,
+                    self.rank_claims.is_claimed(proof.index),
+                )
+            }))
+    }
+}
+
+/// Rank of a user in a period result as claim_rank_reward would pay it.
+/// A user without a period state, or off the leaderboard of a result without a root, is unranked.
+pub fn handler(ctx: Context<GetRank>, proof: Option<RankProof>) -> Result<RankView> {
+    let accts = ctx.accounts;
+    let mut view = RankView {
+        claim_deadline: accts.period_result.claim_deadline,
+        ..RankView::default()
+    };
+    if accts.user_period_state.data_is_empty() {
+        return Ok(view);
+    }
+    let state: Account<PeriodState> = Account::try_from(&accts.user_period_state)?;
+    require!(
+        is_current_layout(&state),
+        BettingError::UnsupportedAccountVersion
+    );
+    if let Some((position, reward, is_claimed)) = accts.standing(&state, &proof)? {
+        let tier = accts
+            .period_result
+            .tier_config()
+            .get(position)
+            .copied()
+            .unwrap_or_default();
+        view.is_ranked = true;
+        view.tier = position as u8;
+        view.reward = reward;
+        view.prize = tier.prize;
+        view.bundle_id = (tier.prize == RankPrize::Bundle as u8).then(|| tier.bundle_id);
+        view.pool_reward = accts.period_result.pool_reward(position);
+        view.is_claimed = is_claimed;
+    }
+    Ok(view)
+}
@@ -1,8 +1,2 @@
-pub mod get_hour_rank;
-pub use get_hour_rank::*;
-
-pub mod get_day_rank;
-pub use get_day_rank::*;
-
-pub mod get_week_rank;
-pub use get_week_rank::*;
+pub mod get_rank;
+pub use get_rank::*;
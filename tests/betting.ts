@@ -11,12 +11,15 @@ import {
   endArena,
   initializeProgram, startArena, userBet,
   openArena,
-  endHour, endDay, endWeek, claimHourRankReward, claimDayRankReward, claimWeekRankReward, claimRefReward, cancelArena, returnBet, partsToNft, buyBundle, openBundle, mintFragment, burnFragments, createFragmentMints, buildNFT, claimEightBoxReward, buyNft, closeArenaState, closeDayResult, closeHourResult, closeWeekResult, closeEightBoxState
+  setPeriodKind, setPeriodVesting, setPeriodPool, setEightBoxPrizes, getCurrentPeriod, createSeason, endSeason, closeSeason, settleLoss, settleWin, DEFAULT_PERIOD_KINDS, DEFAULT_EIGHT_BOX_PRIZES, endPeriod, claimRankReward, claimRefReward, cancelArena, returnBet, partsToNft, buyBundle, openBundle, mintFragment, burnFragments, createFragmentMints, buildNFT, claimEightBoxReward, buyNft, closeArenaState, closePeriodResult, closeEightBoxState, setFeeSplit, migrateAccount, setBetMint, fetchPeriodState, closePeriodLeaderboard, closePeriodState, getRank, withdrawVested, updateConfig, proposeAuthority, acceptAuthority, setRoles, setPaused, triggerRefund, fetchArenaState, distributeFees, fetchGlobalState, claimSeasonTrophy, claimAllEightBoxRewards, fetchPeriodLeaderboard, fetchPeriodResult
 } from "./libs/instructions";
 
-import { delay, getEightBoxId, getPassedDays, getPassedHours, getPassedWeeks } from "./libs/utils";
+import { delay, getEightBoxId, getAcctBalance, waitForClusterTime, getRankLeaf, getMerkleTree } from "./libs/utils";
+import { HOUR_KIND, DAY_KIND, WEEK_KIND, WEEK_PROFIT_KIND, ONE_HOUR_SEC, ONE_DAY_SEC, ONE_WEEK_SEC, EIGHT_BOX_FEEL, PAUSE_BETTING, ARENA_REFUNDING } from "./libs/constants";
+import { getGlobalStateKey, getFeeSplitKey, getArenaStateKey, getUserBetStateKey, getUserPeriodStateKey, getEightBoxConfigKey, getPeriodResultKey, getVestingKey } from "./libs/keys";
 
-import { mintTo } from "@solana/spl-token";
+import { PublicKey } from "@solana/web3.js";
+import { mintTo, NATIVE_MINT, getAssociatedTokenAddress } from "@solana/spl-token";
 import { getEightBoxStateKey } from "../scripts/keys";
 
 chaiUse(chaiAsPromised);
@@ -38,6 +41,23 @@ describe("betting", () => {
 
   const arenaId = 1;
   const cancelledArenaId = 2;
+  const solArenaId = 3;
+  const seasonId = 1;
+
+  // FEEL reward per tier of each period kind
+  const HOUR_REWARDS = [428, 749, 535, 107, 42];
+  const DAY_REWARDS = [10273, 15410, 10273, 2568, 1027, 171, 102];
+  const WEEK_REWARDS = [32363, 43150, 21575, 10787, 4315, 1438, 863, 431, 107];
+  const WEEK_PROFIT_REWARDS = [20000, 5000, 1000];
+
+  // The hour and day periods of the first arena's bets and the season end this long after
+  // the period kinds are set, so their results can be published and claimed in this run.
+  const PERIOD_CUTOFF_DELAY_SEC = 150;
+  let periodCutoff = 0;
+  let epochOffsets = [0, 0, 0, 0];
+  let hourPeriod: anchor.BN = null;
+  let dayPeriod: anchor.BN = null;
+  let dayProofs = new Map<string, any>();
 
   let bundle0_mint = null;
   let bundle6_mint = null;
@@ -70,16 +90,209 @@ describe("betting", () => {
     );
   });
 
+  const fetchConfig = async () => {
+    const globalState = await fetchGlobalState(await getGlobalStateKey());
+    return {
+      treasury: globalState.treasury,
+      btcPythAccount: globalState.btcPythAccount,
+      ethPythAccount: globalState.ethPythAccount,
+      solPythAccount: globalState.solPythAccount,
+      avaxPythAccount: globalState.avaxPythAccount,
+      adaPythAccount: globalState.adaPythAccount,
+      arenaDuration: globalState.arenaDuration,
+      platformFeeRate: globalState.platformFeeRate,
+      referralFeeRate: globalState.referralFeeRate,
+      refundGracePeriod: globalState.refundGracePeriod,
+    };
+  };
+
+  it("Update the refund grace period to two days", async () => {
+    const config = await fetchConfig();
+    await updateConfig(admin, { ...config, refundGracePeriod: new anchor.BN(2 * ONE_DAY_SEC) });
+    const updated = await fetchConfig();
+    assert.isTrue(updated.refundGracePeriod.eqn(2 * ONE_DAY_SEC));
+    assert.isTrue(updated.platformFeeRate.eq(config.platformFeeRate));
+  });
+
+  it("FAIL: Update config as a non authority", async () => {
+    await expect(updateConfig(userA, await fetchConfig())).is.rejected;
+  });
+
+  it("FAIL: Update config with a platform fee over 20%", async () => {
+    const config = await fetchConfig();
+    await expect(updateConfig(admin, { ...config, platformFeeRate: new anchor.BN(2001) })).is.rejected;
+  });
+
+  it("FAIL: Update config with an arena duration over a day", async () => {
+    const config = await fetchConfig();
+    await expect(updateConfig(admin, { ...config, arenaDuration: new anchor.BN(ONE_DAY_SEC + 1) })).is.rejected;
+  });
+
+  it("FAIL: Update config with a refund grace period under an hour", async () => {
+    const config = await fetchConfig();
+    await expect(updateConfig(admin, { ...config, refundGracePeriod: new anchor.BN(ONE_HOUR_SEC - 1) })).is.rejected;
+  });
+
+  it("FAIL: Propose an authority as a non authority", async () => {
+    await expect(proposeAuthority(userA, userA.publicKey)).is.rejected;
+  });
+
+  it("Propose UserA as the authority", async () => {
+    await proposeAuthority(admin, userA.publicKey);
+    const globalState = await fetchGlobalState(await getGlobalStateKey());
+    assert.equal(globalState.pendingAuthority.toBase58(), userA.publicKey.toBase58());
+    assert.equal(globalState.authority.toBase58(), admin.publicKey.toBase58());
+  });
+
+  it("FAIL: UserB accepts the authority proposed to UserA", async () => {
+    await expect(acceptAuthority(userB)).is.rejected;
+  });
+
+  it("UserA accepts the authority and hands it back", async () => {
+    await acceptAuthority(userA);
+    let globalState = await fetchGlobalState(await getGlobalStateKey());
+    assert.equal(globalState.authority.toBase58(), userA.publicKey.toBase58());
+
+    await proposeAuthority(userA, admin.publicKey);
+    await acceptAuthority(admin);
+    globalState = await fetchGlobalState(await getGlobalStateKey());
+    assert.equal(globalState.authority.toBase58(), admin.publicKey.toBase58());
+    assert.equal(globalState.pendingAuthority.toBase58(), PublicKey.default.toBase58());
+  });
+
+  it("FAIL: UserA accepts the authority again", async () => {
+    await expect(acceptAuthority(userA)).is.rejected;
+  });
+
+  const adminRoles = {
+    operator: admin.publicKey,
+    ranker: admin.publicKey,
+    minter: admin.publicKey,
+    treasurer: admin.publicKey,
+    pauser: admin.publicKey,
+  };
+
+  it("FAIL: Set roles as a non authority", async () => {
+    await expect(setRoles(userA, { ...adminRoles, pauser: userA.publicKey })).is.rejected;
+  });
+
+  it("Hand the pauser role to UserD", async () => {
+    await setRoles(admin, { ...adminRoles, pauser: userD.publicKey });
+    const globalState = await fetchGlobalState(await getGlobalStateKey());
+    assert.equal(globalState.pauser.toBase58(), userD.publicKey.toBase58());
+    assert.equal(globalState.operator.toBase58(), admin.publicKey.toBase58());
+  });
+
+  it("Set hour, day, week and weekly profit period kinds", async () => {
+    periodCutoff = Math.floor(Date.now() / 1000) + PERIOD_CUTOFF_DELAY_SEC;
+    epochOffsets = [periodCutoff % ONE_HOUR_SEC, periodCutoff % ONE_DAY_SEC, 0, 0];
+    for (let kind = 0; kind < DEFAULT_PERIOD_KINDS.length; kind ++) {
+      const { length, ranking, claimWindow, tiers } = DEFAULT_PERIOD_KINDS[kind];
+      await setPeriodKind(admin, kind, length, tiers, ranking, claimWindow, epochOffsets[kind]);
+    }
+    hourPeriod = new anchor.BN(Math.floor(periodCutoff / ONE_HOUR_SEC) - 1);
+    dayPeriod = new anchor.BN(Math.floor(periodCutoff / ONE_DAY_SEC) - 1);
+  });
+
+  it("Vest hourly rank rewards over 10 seconds", async () => {
+    await setPeriodVesting(admin, HOUR_KIND, 10);
+  });
+
+  it("Pay 10% of arena fees into the hourly prize pool", async () => {
+    await setPeriodPool(admin, HOUR_KIND, 1000);
+  });
+
+  it("Vest weekly rank rewards over 4 weeks with a 1 week cliff", async () => {
+    await setPeriodVesting(admin, WEEK_KIND, ONE_WEEK_SEC * 4, ONE_WEEK_SEC);
+  });
+
+  it("Pay 20% of arena fees into the weekly prize pool", async () => {
+    await setPeriodPool(admin, WEEK_KIND, 2000);
+  });
+
+  it("FAIL: Pay more than the whole arena fee into prize pools", async () => {
+    await expect(setPeriodPool(admin, DAY_KIND, 9000)).is.rejected;
+  });
+
+  it("Set eight-hour box prizes", async () => {
+    await setEightBoxPrizes(admin);
+  });
+
+  it("Append more than eight eight-hour box prizes", async () => {
+    const prizes = [...DEFAULT_EIGHT_BOX_PRIZES, ...[...Array(8).keys()].map(i => (
+      { volume: new anchor.BN((i + 2) * 1000_000_000), kind: EIGHT_BOX_FEEL, id: 0, amount: new anchor.BN(1_000_000) }
+    ))];
+    await setEightBoxPrizes(admin, prizes);
+    const config = await program.account.eightBoxConfig.fetch(await getEightBoxConfigKey());
+    assert.equal(config.prizeCount, 12);
+  });
+
+  it("FAIL: Drop the first eight-hour box prize", async () => {
+    await expect(setEightBoxPrizes(admin, DEFAULT_EIGHT_BOX_PRIZES.slice(1))).is.rejected;
+  });
+
+  it("FAIL: Set an eight-hour box claim window under a day", async () => {
+    const config = await program.account.eightBoxConfig.fetch(await getEightBoxConfigKey());
+    const prizes = config.prizes.slice(0, config.prizeCount);
+    await expect(setEightBoxPrizes(admin, prizes, ONE_HOUR_SEC)).is.rejected;
+  });
+
+  it("Eight-hour box claim window is set in the config", async () => {
+    const config = await program.account.eightBoxConfig.fetch(await getEightBoxConfigKey());
+    assert.isTrue(config.claimWindow.eqn(ONE_WEEK_SEC));
+  });
+
+  it("Create a two week season ending with the hour of the bets", async () => {
+    await createSeason(admin, seasonId, periodCutoff - ONE_WEEK_SEC * 2, periodCutoff, 1000_000_000, "FEEL Season 1", "https://arweave.net/season-1");
+  });
+
+  it("FAIL: Create a season with a claim window under a day", async () => {
+    const startTime = Math.floor(Date.now() / 1000);
+    await expect(
+      createSeason(admin, seasonId + 1, startTime, startTime + ONE_WEEK_SEC * 2, 1000_000_000, "FEEL Season 2", "https://arweave.net/season-2", ONE_HOUR_SEC)
+    ).is.rejected;
+  });
+
   it("Initialize Fragment Mints", async () => {
     await createFragmentMints(bettingAccounts, admin);
   })
 
+  it("Set Fee Split", async () => {
+    await setFeeSplit(bettingAccounts, admin, [admin.publicKey, userB.publicKey], [7000, 3000]);
+  })
+
   it("Open Arena", async () => {
     const tx = await openArena(bettingAccounts, admin, arenaId);
   })
 
+  it("FAIL: Pause betting as the authority after the pauser role moved to UserD", async () => {
+    await expect(setPaused(admin, PAUSE_BETTING)).is.rejected;
+  });
+
+  it("FAIL: Pause with an unknown pause bit", async () => {
+    await expect(setPaused(userD, 1 << 7)).is.rejected;
+  });
+
+  it("UserD pauses betting", async () => {
+    await setPaused(userD, PAUSE_BETTING);
+    const globalState = await fetchGlobalState(await getGlobalStateKey());
+    assert.equal(globalState.paused, PAUSE_BETTING);
+  });
+
+  it("FAIL: UserA bets while betting is paused", async () => {
+    await expect(
+      userBet(bettingAccounts, userA, userD.publicKey, arenaId, 2500, true /** up */, seasonId)
+    ).is.rejected;
+  });
+
+  it("UserD resumes betting", async () => {
+    await setPaused(userD, 0);
+    const globalState = await fetchGlobalState(await getGlobalStateKey());
+    assert.equal(globalState.paused, 0);
+  });
+
   it("UserA Bet to Up, 2500 USDC", async () => {
-    const tx = await userBet(bettingAccounts, userA, userD.publicKey, arenaId, 2500, true /** up */);
+    const tx = await userBet(bettingAccounts, userA, userD.publicKey, arenaId, 2500, true /** up */, seasonId);
   });
 
   it("UserB Bet to Up, 2000 USDC", async () => {
@@ -96,6 +309,22 @@ describe("betting", () => {
     const tx = await userBet(bettingAccounts, userC, userD.publicKey, arenaId, 1500, false /** down */);
   });
 
+  it("Enable SOL bets", async () => {
+    await setBetMint(bettingAccounts, admin, NATIVE_MINT, 1);
+  });
+
+  it("Open SOL Arena", async () => {
+    await openArena(bettingAccounts, admin, solArenaId, NATIVE_MINT);
+  });
+
+  it("UserB Bet 1 SOL, its USDC value counts as volume", async () => {
+    const stateKey = await getUserPeriodStateKey(HOUR_KIND, userB.publicKey, await getCurrentPeriod(HOUR_KIND));
+    const before = (await fetchPeriodState(stateKey)).betAmount;
+    await userBet(bettingAccounts, userB, userD.publicKey, solArenaId, 1, true /** up */, null, NATIVE_MINT);
+    const after = (await fetchPeriodState(stateKey)).betAmount;
+    assert.isTrue(after.gt(before));
+  });
+
   xit("Remaining users Bet to random with random amount", async () => {
     for (let i = 0; i < remainingUsers.length; i ++) {
       await userBet(bettingAccounts,
@@ -108,6 +337,10 @@ describe("betting", () => {
     }
   });
 
+  it("FAIL: UserA triggers a refund before the refund grace period is over", async () => {
+    await expect(triggerRefund(bettingAccounts, userA, arenaId)).is.rejected;
+  });
+
   it("Start Arena", async () => {
     const tx = await startArena(bettingAccounts, admin, arenaId);
   });
@@ -123,35 +356,253 @@ describe("betting", () => {
   });
 
   it("UserA claim Reward", async () => {
-    const tx = await claimReward(bettingAccounts, userA, userD, arenaId);
+    const tx = await claimReward(bettingAccounts, userA, userD, arenaId, seasonId);
   })
 
+  it("Settle UserC's win before the claim", async () => {
+    await settleWin(admin, userC.publicKey, arenaId);
+    const userBetState = await program.account.userBetState.fetch(await getUserBetStateKey(arenaId, userC.publicKey));
+    assert.equal(userBetState.isSettled, 1);
+    assert.isTrue(userBetState.reward.gt(userBetState.betAmount));
+  });
+
+  it("FAIL: Settle UserC's win again", async () => {
+    await expect(
+      settleWin(admin, userC.publicKey, arenaId)
+    ).is.rejected;
+  });
+
   it("UserC claim Reward", async () => {
     const tx = await claimReward(bettingAccounts, userC, userD, arenaId);
   })
+
+  it("Winning claims never pay out more than the arena booked", async () => {
+    const arena = await program.account.arenaState.fetch(await getArenaStateKey(arenaId));
+    assert.isTrue(arena.winningsClaimed.lte(arena.winnings));
+    assert.isTrue(arena.referralFeesClaimed.lte(arena.referralFees));
+  })
+
+  it("Settle losing bets", async () => {
+    const arenaState = await program.account.arenaState.fetch(await getArenaStateKey(arenaId));
+    for (const user of [userA, userB, userC]) {
+      const userBetState = await program.account.userBetState.fetchNullable(
+        await getUserBetStateKey(arenaId, user.publicKey)
+      );
+      if (userBetState === null || userBetState.isUp == arenaState.betResult) continue;
+      await settleLoss(admin, user.publicKey, arenaId);
+    }
+  })
+
+  it("FAIL: Distribute fees to the recipients out of FeeSplit order", async () => {
+    await expect(distributeFees(bettingAccounts, userC, [userB.publicKey, admin.publicKey])).is.rejected;
+  });
+
+  it("FAIL: Distribute fees to only the first recipient", async () => {
+    await expect(distributeFees(bettingAccounts, userC, [admin.publicKey])).is.rejected;
+  });
+
+  it("Distribute the arena fees 70/30, the payouts add up to the fee vault", async () => {
+    const feeVaultAta = await getAssociatedTokenAddress(bettingAccounts.bettingMint, await getFeeSplitKey(), true);
+    const balanceOf = async (ata: PublicKey) => new anchor.BN((await getAcctBalance(ata)).amount);
+
+    const vaultBefore = await balanceOf(feeVaultAta);
+    const adminBefore = await balanceOf(admin.bettingMintAta);
+    const userBBefore = await balanceOf(userB.bettingMintAta);
+    assert.isTrue(vaultBefore.gtn(0));
+
+    await distributeFees(bettingAccounts, userC, [admin.publicKey, userB.publicKey]);
+
+    const adminPaid = (await balanceOf(admin.bettingMintAta)).sub(adminBefore);
+    const userBPaid = (await balanceOf(userB.bettingMintAta)).sub(userBBefore);
+    assert.isTrue(adminPaid.eq(vaultBefore.muln(7000).divn(10000)));
+    assert.isTrue(adminPaid.add(userBPaid).eq(vaultBefore));
+    assert.isTrue((await balanceOf(feeVaultAta)).eqn(0));
+  });
+
+  it("FAIL: End weekly profit ranking without a Merkle root", async () => {
+    await expect(
+      endPeriod(bettingAccounts, admin, WEEK_PROFIT_KIND, WEEK_PROFIT_REWARDS, (await getCurrentPeriod(WEEK_PROFIT_KIND)).subn(1))
+    ).is.rejected;
+  });
   
+  it("FAIL: End season before it is over", async () => {
+    await expect(
+      endSeason(bettingAccounts, admin, seasonId, [50000, 25000, 10000])
+    ).is.rejected;
+  });
+
+  it("FAIL: Close the season before it is ended", async () => {
+    await expect(
+      closeSeason(bettingAccounts, admin, seasonId)
+    ).is.rejected;
+  });
+
+  it("FAIL: End Hour before the hour is over", async () => {
+    await expect(
+      endPeriod(bettingAccounts, admin, HOUR_KIND, HOUR_REWARDS, await getCurrentPeriod(HOUR_KIND))
+    ).is.rejected;
+  });
+
+  it("FAIL: End Day before the day is over", async () => {
+    await expect(
+      endPeriod(bettingAccounts, admin, DAY_KIND, DAY_REWARDS, await getCurrentPeriod(DAY_KIND))
+    ).is.rejected;
+  });
+
+  it("FAIL: End Week before the week is over", async () => {
+    await expect(
+      endPeriod(bettingAccounts, admin, WEEK_KIND, WEEK_REWARDS, await getCurrentPeriod(WEEK_KIND))
+    ).is.rejected;
+  });
+
+  it("FAIL: Close the hour leaderboard before its claim window is over", async () => {
+    await expect(
+      closePeriodLeaderboard(admin, userA.publicKey, HOUR_KIND, await getCurrentPeriod(HOUR_KIND))
+    ).is.rejected;
+  });
+
+  it("FAIL: Close UserA's hour state while its period runs", async () => {
+    await expect(
+      closePeriodState(userB, userA.publicKey, HOUR_KIND, await getCurrentPeriod(HOUR_KIND))
+    ).is.rejected;
+  });
+
+  it("Wait for the hour and day of the bets and the season to end", async () => {
+    await waitForClusterTime(provider.connection, periodCutoff);
+  });
+
+  it("End the hour before the bets, nobody bet in it", async () => {
+    await endPeriod(bettingAccounts, admin, HOUR_KIND, HOUR_REWARDS, hourPeriod.subn(1));
+    const result = await fetchPeriodResult(await getPeriodResultKey(HOUR_KIND, hourPeriod.subn(1)));
+    assert.isTrue(result.budget.eqn(0));
+  });
+
+  it("Close the empty hour result without waiting for a claim window", async () => {
+    // the claim window of an empty result ends when it is published
+    await delay(2000);
+    await closePeriodResult(bettingAccounts, admin, HOUR_KIND, hourPeriod.subn(1));
+    assert.isNull(await fetchPeriodResult(await getPeriodResultKey(HOUR_KIND, hourPeriod.subn(1))));
+  });
+
   it("End Hour", async () => {
-    await endHour(bettingAccounts, admin);
+    await endPeriod(bettingAccounts, admin, HOUR_KIND, HOUR_REWARDS, hourPeriod);
+    const result = await fetchPeriodResult(await getPeriodResultKey(HOUR_KIND, hourPeriod));
+    assert.isTrue(result.budget.gtn(0));
+    assert.isTrue(result.poolBudget.gtn(0));
+  });
+
+  it("FAIL: End Hour twice", async () => {
+    await expect(
+      endPeriod(bettingAccounts, admin, HOUR_KIND, HOUR_REWARDS, hourPeriod)
+    ).is.rejected;
+  });
+
+  it("UserA ranks in the hour with a share of the prize pool", async () => {
+    const rank = await getRank(userA.publicKey, HOUR_KIND, hourPeriod);
+    assert.isTrue(rank.isRanked);
+    assert.isTrue(rank.reward.gtn(0));
+    assert.isTrue(rank.poolReward.gtn(0));
+    assert.isFalse(rank.isClaimed);
   });
 
-  it("End Day", async () => {
-    await endDay(bettingAccounts, admin);
+  it("UserD did not bet in the hour and is unranked", async () => {
+    const rank = await getRank(userD.publicKey, HOUR_KIND, hourPeriod);
+    assert.isFalse(rank.isRanked);
   });
 
-  it("End Week", async () => {
-    await endWeek(bettingAccounts, admin);
+  it("Claim hour rank reward, the FEEL vests and the pool share is paid", async () => {
+    const poolBefore = new anchor.BN((await getAcctBalance(userA.bettingMintAta)).amount);
+    await claimRankReward(bettingAccounts, userA, HOUR_KIND, hourPeriod);
+    const poolAfter = new anchor.BN((await getAcctBalance(userA.bettingMintAta)).amount);
+    assert.isTrue(poolAfter.gt(poolBefore));
+    assert.isTrue((await getRank(userA.publicKey, HOUR_KIND, hourPeriod)).isClaimed);
+    const vesting = await program.account.vesting.fetch(
+      await getVestingKey(await getPeriodResultKey(HOUR_KIND, hourPeriod), userA.publicKey)
+    );
+    assert.isTrue(vesting.total.gtn(0));
   });
 
-  it("Claim hour rank reward", async () => {
-    await claimHourRankReward(bettingAccounts, userA);
+  it("FAIL: Claim hour rank reward twice", async () => {
+    await expect(
+      claimRankReward(bettingAccounts, userA, HOUR_KIND, hourPeriod)
+    ).is.rejected;
   });
 
-  it("Claim day rank reward", async () => {
-    await claimDayRankReward(bettingAccounts, userA);
+  it("Withdraw the vested hour reward", async () => {
+    await delay(11 * 1000);
+    const before = new anchor.BN((await getAcctBalance(userA.feelAta)).amount);
+    await withdrawVested(bettingAccounts, userA, HOUR_KIND, hourPeriod);
+    const after = new anchor.BN((await getAcctBalance(userA.feelAta)).amount);
+    assert.isTrue(after.gt(before));
   });
 
-  it("Claim week rank reward", async () => {
-    await claimWeekRankReward(bettingAccounts, userA);
+  it("FAIL: Close the hour result before its claim window is over", async () => {
+    await expect(
+      closePeriodResult(bettingAccounts, admin, HOUR_KIND, hourPeriod)
+    ).is.rejected;
+  });
+
+  it("End Day with a Merkle root of its top 3", async () => {
+    const entries = (await fetchPeriodLeaderboard(DAY_KIND, dayPeriod)).slice(0, 3);
+    const rewards = entries.map((_, i) => new anchor.BN(DAY_REWARDS[i]));
+    const leaves = entries.map((entry, i) => getRankLeaf(i, entry.user, i + 1, rewards[i]));
+    const { root, proofs } = getMerkleTree(leaves);
+    entries.forEach((entry, i) => dayProofs.set(entry.user.toBase58(), {
+      index: i,
+      rank: i + 1,
+      reward: rewards[i],
+      proof: proofs[i].map(node => [...node]),
+    }));
+    const merkleRoot = {
+      root: [...root],
+      leafCount: leaves.length,
+      totalReward: rewards.reduce((total, reward) => total.add(reward), new anchor.BN(0)),
+    };
+    await endPeriod(bettingAccounts, admin, DAY_KIND, DAY_REWARDS, dayPeriod, merkleRoot);
+  });
+
+  it("UserA ranks in the day with its proof", async () => {
+    const proof = dayProofs.get(userA.publicKey.toBase58());
+    const rank = await getRank(userA.publicKey, DAY_KIND, dayPeriod, proof);
+    assert.isTrue(rank.isRanked);
+    assert.isTrue(rank.reward.eq(proof.reward));
+    assert.isFalse((await getRank(userA.publicKey, DAY_KIND, dayPeriod)).isRanked);
+  });
+
+  it("FAIL: Claim day rank reward with UserA's proof as UserC", async () => {
+    await expect(
+      claimRankReward(bettingAccounts, userC, DAY_KIND, dayPeriod, dayProofs.get(userA.publicKey.toBase58()))
+    ).is.rejected;
+  });
+
+  it("Claim day rank reward with a proof", async () => {
+    const proof = dayProofs.get(userA.publicKey.toBase58());
+    const before = new anchor.BN((await getAcctBalance(userA.feelAta)).amount);
+    await claimRankReward(bettingAccounts, userA, DAY_KIND, dayPeriod, proof);
+    const after = new anchor.BN((await getAcctBalance(userA.feelAta)).amount);
+    assert.isTrue(after.sub(before).eq(proof.reward));
+    assert.isTrue((await getRank(userA.publicKey, DAY_KIND, dayPeriod, proof)).isClaimed);
+  });
+
+  it("FAIL: Claim day rank reward with the same proof twice", async () => {
+    await expect(
+      claimRankReward(bettingAccounts, userA, DAY_KIND, dayPeriod, dayProofs.get(userA.publicKey.toBase58()))
+    ).is.rejected;
+  });
+
+  it("End season", async () => {
+    await endSeason(bettingAccounts, admin, seasonId, [50000, 25000, 10000]);
+  });
+
+  it("UserA claims the season trophy", async () => {
+    const before = new anchor.BN((await getAcctBalance(userA.feelAta)).amount);
+    await claimSeasonTrophy(bettingAccounts, userA, seasonId);
+    const after = new anchor.BN((await getAcctBalance(userA.feelAta)).amount);
+    assert.isTrue(after.sub(before).eqn(50000));
+  });
+
+  it("FAIL: UserA claims the season trophy twice", async () => {
+    await expect(claimSeasonTrophy(bettingAccounts, userA, seasonId)).is.rejected;
   });
 
   it("Claim Ref reward", async () => {
@@ -205,6 +656,20 @@ describe("betting", () => {
     await claimEightBoxReward(bettingAccounts, userA, 0);
   })
 
+  it("UserA claims the rest of the unlocked 8h box prizes at once", async () => {
+    await claimEightBoxReward(bettingAccounts, userA, 1);
+    await claimEightBoxReward(bettingAccounts, userA, 2);
+    await claimAllEightBoxRewards(bettingAccounts, userA);
+    const boxState = await program.account.eightBoxState.fetch(
+      await getEightBoxStateKey(userA.publicKey, getEightBoxId(Date.now()))
+    );
+    const config = await program.account.eightBoxConfig.fetch(await getEightBoxConfigKey());
+    for (let prizeId = 0; prizeId < config.prizeCount; prizeId ++) {
+      const isUnlocked = boxState.betAmount.gte(config.prizes[prizeId].volume);
+      assert.equal(boxState.claimedStatus.testn(prizeId), isUnlocked);
+    }
+  })
+
   it("UserA mints Fragment and burn", async () => {
     for (let i = 1; i <= 9; i ++) 
       await mintFragment(bettingAccounts, admin, i);
@@ -217,24 +682,59 @@ describe("betting", () => {
     await closeArenaState(admin, arenaId)
   })
 
-  it("Close HourResult Account", async () => {
-    let hour = getPassedHours(Date.now());
-    await closeHourResult(admin, hour)
-  })
-  
-  it("Close DayResult Account", async () => {
-    let day = getPassedDays(Date.now());
-    await closeDayResult(admin, day)
-  })
-  
-  it("Close WeekResult Account", async () => {
-    let week = getPassedWeeks(Date.now());
-    await closeWeekResult(admin, week)
+  // loaded from tests/fixtures, written by the baseline program without a version byte
+  const baselineNftBuildState = new PublicKey("GZbgoeHukxSp72t6YikxNUbRpy96DcgLWw4BJiYBb77a");
+
+  it("Migrate a baseline NftBuildState", async () => {
+    await migrateAccount(admin, baselineNftBuildState);
+    const buildState = await program.account.nftBuildState.fetch(baselineNftBuildState);
+    assert.equal(buildState.version, 1);
+    assert.equal(buildState.buildState, 0b111111111);
+    assert.equal(buildState.user.toBase58(), "13oRGVvAyxcCgyETktkCswVomPMZGYmmv5tbGxiCCWmj");
+  });
+
+  it("FAIL: Migrate the NftBuildState again", async () => {
+    await expect(migrateAccount(admin, baselineNftBuildState)).is.rejected;
+  });
+
+  // loaded from tests/fixtures, opened in 2020 and never settled, the test wallet bet in it
+  const stalledArenaId = 100;
+
+  it("FAIL: UserA triggers a refund of the stalled arena without a bet in it", async () => {
+    await expect(triggerRefund(bettingAccounts, userA, stalledArenaId)).is.rejected;
+  });
+
+  it("The test wallet triggers a refund of the stalled arena", async () => {
+    const walletUser = new User();
+    walletUser.keypair = (provider.wallet as anchor.Wallet).payer;
+    walletUser.publicKey = walletUser.keypair.publicKey;
+    await triggerRefund(bettingAccounts, walletUser, stalledArenaId);
+    const arenaState = await fetchArenaState(await getArenaStateKey(stalledArenaId));
+    assert.equal(arenaState.status, ARENA_REFUNDING);
+  });
+
+  it("FAIL: Set a tier with an unknown bundle id", async () => {
+    const { length, ranking, tiers, claimWindow } = DEFAULT_PERIOD_KINDS[HOUR_KIND];
+    const badTiers = tiers.map((tier, i) => i == 0 ? { ...tier, bundleId: 6 } : tier);
+    await expect(
+      setPeriodKind(admin, HOUR_KIND, length, badTiers, ranking, claimWindow, epochOffsets[HOUR_KIND])
+    ).is.rejected;
+  });
+
+  it("FAIL: Set a claim window shorter than a day", async () => {
+    const { length, ranking, tiers } = DEFAULT_PERIOD_KINDS[HOUR_KIND];
+    await expect(
+      setPeriodKind(admin, HOUR_KIND, length, tiers, ranking, ONE_HOUR_SEC, epochOffsets[HOUR_KIND])
+    ).is.rejected;
+  });
+
+  it("FAIL: Claim the same 8h box prize twice", async () => {
+    await expect(claimEightBoxReward(bettingAccounts, userA, 0)).is.rejected;
   })
-  
-  it("Close EightBoxState Account", async () => {  
+
+  it("FAIL: Close EightBoxState Account before its claim window is over", async () => {  
     let eight_box_id = getEightBoxId(Date.now());
-    await closeEightBoxState(admin, userA.publicKey, eight_box_id)
+    await expect(closeEightBoxState(admin, userA.publicKey, eight_box_id)).is.rejected;
   })
   
 });
@@ -4,19 +4,31 @@ export const USER_STATE_SEED = "USER_STATE_SEED";
 export const ARENA_STATE_SEED = "ARENA_STATE_SEED";
 export const USER_BET_SEED = "USER_BET_SEED";
 
-export const HOUR_STATE_SEED = "HOUR_STATE_SEED";
-export const DAY_STATE_SEED = "DAY_STATE_SEED";
-export const WEEK_STATE_SEED = "WEEK_STATE_SEED";
 export const EIGHT_BOX_STATE_SEED = "EIGHT_BOX_STATE_SEED";
+export const EIGHT_BOX_CONFIG_SEED = "EIGHT_BOX_CONFIG_SEED";
 
-export const HOUR_RESULT_SEED = "HOUR_RESULT_SEED";
-export const DAY_RESULT_SEED = "DAY_RESULT_SEED";
-export const WEEK_RESULT_SEED = "WEEK_RESULT_SEED";
+export const PERIOD_CONFIG_SEED = "PERIOD_CONFIG_SEED";
+export const PERIOD_STATE_SEED = "PERIOD_STATE_SEED";
+export const PERIOD_RESULT_SEED = "PERIOD_RESULT_SEED";
+export const PERIOD_LEADERBOARD_SEED = "PERIOD_LEADERBOARD_SEED";
+
+// hour, day and week accounts of the baseline program, indexed by legacy kind
+export const LEGACY_STATE_SEEDS = ["HOUR_STATE_SEED", "DAY_STATE_SEED", "WEEK_STATE_SEED"];
+export const LEGACY_RESULT_SEEDS = ["HOUR_RESULT_SEED", "DAY_RESULT_SEED", "WEEK_RESULT_SEED"];
+
+export const SEASON_SEED = "SEASON_SEED";
+export const SEASON_STATE_SEED = "SEASON_STATE_SEED";
+export const SEASON_LEADERBOARD_SEED = "SEASON_LEADERBOARD_SEED";
+export const RANK_CLAIMS_SEED = "RANK_CLAIMS_SEED";
+export const VESTING_SEED = "VESTING_SEED";
 
 export const FRAGMENT_MINTER_SEED = "FRAGMENT_MINTER_SEED";
 export const NFT_MINTER_SEED = "NFT_MINTER_SEED";
 export const BUNDLE_MINTER_SEED = "BUNDLE_MINTER_SEED";
 export const NFT_BUILD_STATE_SEED = "NFT_BUILD_STATE_SEED";
+export const FEE_SPLIT_SEED = "FEE_SPLIT_SEED";
+export const BET_MINT_SEED = "BET_MINT_SEED";
+export const UNWRAP_SEED = "UNWRAP_SEED";
 
 export const USDC_DECIMALS = 6;
 
@@ -28,6 +40,36 @@ export const ONE_HOUR_SEC = 60 * 60;
 export const ONE_DAY_SEC = ONE_HOUR_SEC * 24;
 export const ONE_WEEK_SEC = ONE_DAY_SEC * 7;
 
+// period kinds set up by the tests, ids are indexes in PeriodConfig
+export const HOUR_KIND = 0;
+export const DAY_KIND = 1;
+export const WEEK_KIND = 2;
+export const WEEK_PROFIT_KIND = 3;
+
+// Ranking
+export const RANKING_VOLUME = 0;
+export const RANKING_PROFIT = 1;
+
+// RankPrize
+export const PRIZE_NONE = 0;
+export const PRIZE_BUNDLE = 1;
+export const PRIZE_NFT = 2;
+
+// EightBoxPrizeKind
+export const EIGHT_BOX_BUNDLE = 0;
+export const EIGHT_BOX_FEEL = 1;
+export const EIGHT_BOX_FRAGMENT = 2;
+
+// ArenaStatus
+export const ARENA_REFUNDING = 5;
+
+// pause bits of GlobalState
+export const PAUSE_BETTING = 1 << 0;
+export const PAUSE_SETTLEMENT = 1 << 1;
+export const PAUSE_CLAIMS = 1 << 2;
+export const PAUSE_SHOP = 1 << 3;
+export const PAUSE_BUNDLE_OPENING = 1 << 4;
+
 export const TREASURY = "5de42qodN5hDg2yYWVzFcHsVzv2dNGLt29QymSeY1Pzn";
 export const MetadataProgramId = "metaqbxxUerdq28cj1RbAWkYQm3ybzjb6a8bt518x1s";
 
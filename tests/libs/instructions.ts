@@ -17,14 +17,15 @@ import {
   createInitializeMintInstruction,
   createInitializeAccountInstruction,
   createAssociatedTokenAccountInstruction,
-  MINT_SIZE
+  MINT_SIZE,
+  NATIVE_MINT
 } from "@solana/spl-token";
 
 import { Metadata } from '@metaplex-foundation/mpl-token-metadata'
 import bs58 from 'bs58';
 import crypto from 'crypto';
 import * as anchor from "@project-serum/anchor";
-import { IdlAccounts } from "@project-serum/anchor";
+import { IdlAccounts, IdlTypes } from "@project-serum/anchor";
 import BN from 'bn.js';
 import { Betting } from "../../target/types/betting";
 import * as Constants from "./constants";
@@ -32,7 +33,7 @@ import * as keys from "./keys";
 import { User } from "./user";
 import { BettingAccounts } from "./accounts";
 import { assert } from "chai";
-import { delay, sendOrSimulateTransaction, getHashArr, getAssocTokenAcct, getPassedHours, getPassedDays, getPassedWeeks, getEightBoxId, getTransactionSize, getAcctInfo } from "./utils";
+import { delay, sendOrSimulateTransaction, getHashArr, getAssocTokenAcct, getPeriodIndex, getEightBoxId, getTransactionSize, getAcctInfo } from "./utils";
 
 const program = anchor.workspace.Betting as anchor.Program<Betting>;
 const connection = program.provider.connection;
@@ -58,10 +59,12 @@ export const initializeProgram = async (accts: BettingAccounts, admin: User) =>
       globalState: globalStateKey,
       escrowAta: accts.escrowAta,
       feelVaultAta,
+      betMint: await keys.getBetMintKey(accts.bettingMint),
       tokenMint: accts.bettingMint,
       rankMint: accts.rankMint,
       treasury: new PublicKey(Constants.TREASURY),
       tokenProgram: TOKEN_PROGRAM_ID,
+      feelTokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
@@ -81,7 +84,7 @@ export const createFragmentMints = async (accts: BettingAccounts, admin: User) =
   let transaction = new Transaction().add(await program.methods
     .createFragmentMints()
     .accounts({
-      authority: admin.publicKey,
+      minter: admin.publicKey,
       globalState: globalStateKey,
       fragment1Mint: fragmentMintKeys[0],
       fragment2Mint: fragmentMintKeys[1],
@@ -107,13 +110,304 @@ export const createFragmentMints = async (accts: BettingAccounts, admin: User) =
 };
 
 
-export const openArena = async (accts: BettingAccounts, admin: User, arenaId: number) => {
+export const updateConfig = async (
+  admin: User,
+  config: IdlTypes<Betting>["Config"]
+) => {
   await sendOrSimulateTransaction(await program.methods
-    .openArena(new BN(arenaId))
+    .updateConfig(config)
+    .accounts({
+      authority: admin.publicKey,
+      globalState: await keys.getGlobalStateKey(),
+    })
+    .signers([admin.keypair])
+    .transaction(),
+    [admin.keypair],
+    connection
+  );
+};
+
+export const proposeAuthority = async (admin: User, newAuthority: PublicKey) => {
+  await sendOrSimulateTransaction(await program.methods
+    .proposeAuthority(newAuthority)
+    .accounts({
+      authority: admin.publicKey,
+      globalState: await keys.getGlobalStateKey(),
+    })
+    .signers([admin.keypair])
+    .transaction(),
+    [admin.keypair],
+    connection
+  );
+};
+
+export const acceptAuthority = async (newAdmin: User) => {
+  await sendOrSimulateTransaction(await program.methods
+    .acceptAuthority()
+    .accounts({
+      pendingAuthority: newAdmin.publicKey,
+      globalState: await keys.getGlobalStateKey(),
+    })
+    .signers([newAdmin.keypair])
+    .transaction(),
+    [newAdmin.keypair],
+    connection
+  );
+};
+
+export const setRoles = async (
+  admin: User,
+  roles: IdlTypes<Betting>["Roles"]
+) => {
+  await sendOrSimulateTransaction(await program.methods
+    .setRoles(roles)
+    .accounts({
+      authority: admin.publicKey,
+      globalState: await keys.getGlobalStateKey(),
+    })
+    .signers([admin.keypair])
+    .transaction(),
+    [admin.keypair],
+    connection
+  );
+};
+
+export const setPaused = async (pauser: User, paused: number) => {
+  await sendOrSimulateTransaction(await program.methods
+    .setPaused(paused)
+    .accounts({
+      pauser: pauser.publicKey,
+      globalState: await keys.getGlobalStateKey(),
+    })
+    .signers([pauser.keypair])
+    .transaction(),
+    [pauser.keypair],
+    connection
+  );
+};
+
+export const setBetMint = async (
+  accts: BettingAccounts,
+  admin: User,
+  mint: PublicKey,
+  isEnabled: number
+) => {
+  const globalStateKey = await keys.getGlobalStateKey();
+  const feeSplitKey = await keys.getFeeSplitKey();
+  await sendOrSimulateTransaction(await program.methods
+    .setBetMint(isEnabled)
+    .accounts({
+      authority: admin.publicKey,
+      globalState: globalStateKey,
+      betMint: await keys.getBetMintKey(mint),
+      escrowAta: await getAssociatedTokenAddress(mint, globalStateKey, true),
+      feeSplit: feeSplitKey,
+      feeVaultAta: await getAssociatedTokenAddress(mint, feeSplitKey, true),
+      mint,
+      tokenMint: accts.bettingMint,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .signers([admin.keypair])
+    .transaction(),
+    [admin.keypair],
+    connection
+  );
+};
+
+export const setFeeSplit = async (
+  accts: BettingAccounts,
+  admin: User,
+  recipients: PublicKey[],
+  shares: number[]
+) => {
+  const feeSplitKey = await keys.getFeeSplitKey();
+  await sendOrSimulateTransaction(await program.methods
+    .setFeeSplit(recipients, shares.map((share) => new BN(share)))
+    .accounts({
+      authority: admin.publicKey,
+      globalState: await keys.getGlobalStateKey(),
+      feeSplit: feeSplitKey,
+      feeVaultAta: await getAssociatedTokenAddress(accts.bettingMint, feeSplitKey, true),
+      tokenMint: accts.bettingMint,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .signers([admin.keypair])
+    .transaction(),
+    [admin.keypair],
+    connection
+  );
+};
+
+// recipients are the wallets passed to setFeeSplit, in the same order
+// period kinds set up by the tests: hourly, daily and weekly volume leaderboards and a weekly profit one
+export const DEFAULT_PERIOD_KINDS = [
+  {
+    length: Constants.ONE_HOUR_SEC,
+    ranking: Constants.RANKING_VOLUME,
+    claimWindow: Constants.ONE_DAY_SEC * 7,
+    tiers: [
+      // the hourly prize pool goes to the top 3
+      { lastRank: 1, prize: Constants.PRIZE_BUNDLE, bundleId: 0, poolShare: 5000 },
+      { lastRank: 2, prize: Constants.PRIZE_NONE, bundleId: 0, poolShare: 3000 },
+      { lastRank: 3, prize: Constants.PRIZE_NONE, bundleId: 0, poolShare: 2000 },
+      ...[5, 10].map(lastRank => ({ lastRank, prize: Constants.PRIZE_NONE, bundleId: 0, poolShare: 0 })),
+    ],
+  },
+  {
+    length: Constants.ONE_DAY_SEC,
+    ranking: Constants.RANKING_VOLUME,
+    claimWindow: Constants.ONE_WEEK_SEC * 2,
+    tiers: [
+      { lastRank: 1, prize: Constants.PRIZE_BUNDLE, bundleId: 4, poolShare: 0 },
+      ...[2, 3, 5, 10, 25, 50].map(lastRank => ({ lastRank, prize: Constants.PRIZE_NONE, bundleId: 0, poolShare: 0 })),
+    ],
+  },
+  {
+    length: Constants.ONE_WEEK_SEC,
+    ranking: Constants.RANKING_VOLUME,
+    claimWindow: Constants.ONE_WEEK_SEC * 4,
+    tiers: [
+      // 90% of the weekly prize pool goes to the top 10
+      { lastRank: 1, prize: Constants.PRIZE_NFT, bundleId: 0, poolShare: 3000 },
+      { lastRank: 2, prize: Constants.PRIZE_BUNDLE, bundleId: 4, poolShare: 2000 },
+      { lastRank: 3, prize: Constants.PRIZE_BUNDLE, bundleId: 4, poolShare: 1000 },
+      { lastRank: 5, prize: Constants.PRIZE_NONE, bundleId: 0, poolShare: 1500 },
+      { lastRank: 10, prize: Constants.PRIZE_NONE, bundleId: 0, poolShare: 1500 },
+      ...[25, 50, 100, 250].map(lastRank => ({ lastRank, prize: Constants.PRIZE_NONE, bundleId: 0, poolShare: 0 })),
+    ],
+  },
+  {
+    length: Constants.ONE_WEEK_SEC,
+    ranking: Constants.RANKING_PROFIT,
+    claimWindow: Constants.ONE_WEEK_SEC * 4,
+    tiers: [
+      { lastRank: 1, prize: Constants.PRIZE_BUNDLE, bundleId: 4, poolShare: 0 },
+      ...[3, 10].map(lastRank => ({ lastRank, prize: Constants.PRIZE_NONE, bundleId: 0, poolShare: 0 })),
+    ],
+  },
+];
+
+export const setPeriodKind = async (
+  admin: User,
+  kind: number,
+  length: number,
+  tiers: IdlTypes<Betting>["PeriodTier"][],
+  ranking: number = Constants.RANKING_VOLUME,
+  claimWindow: number = Constants.ONE_WEEK_SEC * 4,
+  epochOffset: number = 0,
+  isEnabled: boolean = true
+) => {
+  await sendOrSimulateTransaction(await program.methods
+    .setPeriodKind(kind, new BN(length), new BN(epochOffset), isEnabled ? 1 : 0, ranking, new BN(claimWindow), tiers)
+    .accounts({
+      authority: admin.publicKey,
+      globalState: await keys.getGlobalStateKey(),
+      periodConfig: await keys.getPeriodConfigKey(),
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .signers([admin.keypair])
+    .transaction(),
+    [admin.keypair],
+    connection
+  );
+};
+
+// duration 0 pays rank rewards immediately
+export const setPeriodVesting = async (
+  admin: User,
+  kind: number,
+  duration: number,
+  cliff: number = 0
+) => {
+  await sendOrSimulateTransaction(await program.methods
+    .setPeriodVesting(kind, new BN(duration), new BN(cliff))
+    .accounts({
+      authority: admin.publicKey,
+      globalState: await keys.getGlobalStateKey(),
+      periodConfig: await keys.getPeriodConfigKey(),
+    })
+    .signers([admin.keypair])
+    .transaction(),
+    [admin.keypair],
+    connection
+  );
+};
+
+// share of each arena fee paid into the kind's prize pool, over 10000
+export const setPeriodPool = async (
+  admin: User,
+  kind: number,
+  feeRate: number
+) => {
+  await sendOrSimulateTransaction(await program.methods
+    .setPeriodPool(kind, feeRate)
     .accounts({
       authority: admin.publicKey,
       globalState: await keys.getGlobalStateKey(),
+      periodConfig: await keys.getPeriodConfigKey(),
+    })
+    .signers([admin.keypair])
+    .transaction(),
+    [admin.keypair],
+    connection
+  );
+};
+
+// current period of a configured kind
+export const getCurrentPeriod = async (kind: number): Promise<BN> => {
+  const config = await program.account.periodConfig.fetch(await keys.getPeriodConfigKey());
+  const periodKind = config.kinds[kind];
+  return getPeriodIndex(Date.now(), periodKind.length, periodKind.epochOffset);
+};
+
+export const distributeFees = async (
+  accts: BettingAccounts,
+  payer: User,
+  recipients: PublicKey[]
+) => {
+  const feeSplitKey = await keys.getFeeSplitKey();
+  await sendOrSimulateTransaction(await program.methods
+    .distributeFees()
+    .accounts({
+      globalState: await keys.getGlobalStateKey(),
+      feeSplit: feeSplitKey,
+      feeVaultAta: await getAssociatedTokenAddress(accts.bettingMint, feeSplitKey, true),
+      betMint: await keys.getBetMintKey(accts.bettingMint),
+      tokenMint: accts.bettingMint,
+      tokenProgram: TOKEN_PROGRAM_ID,
+    })
+    .remainingAccounts(await Promise.all(recipients.map(async (recipient) => ({
+      pubkey: await getAssociatedTokenAddress(accts.bettingMint, recipient),
+      isSigner: false,
+      isWritable: true,
+    }))))
+    .signers([payer.keypair])
+    .transaction(),
+    [payer.keypair],
+    connection
+  );
+};
+
+export const openArena = async (
+  accts: BettingAccounts,
+  admin: User,
+  arenaId: number,
+  mint: PublicKey = accts.bettingMint
+) => {
+  await sendOrSimulateTransaction(await program.methods
+    .openArena(new BN(arenaId))
+    .accounts({
+      operator: admin.publicKey,
+      globalState: await keys.getGlobalStateKey(),
       arenaState: await keys.getArenaStateKey(arenaId),
+      betMint: await keys.getBetMintKey(mint),
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
     })
@@ -128,7 +422,7 @@ export const startArena = async (accts: BettingAccounts, admin: User, arenaId: n
   await sendOrSimulateTransaction(await program.methods
     .startArena(new BN(arenaId))
     .accounts({
-      authority: admin.publicKey,
+      operator: admin.publicKey,
       globalState: await keys.getGlobalStateKey(),
       arenaState: await keys.getArenaStateKey(arenaId),
       solPythAccount: new PublicKey(Constants.SOL_PYTH_ACCOUNT),
@@ -146,7 +440,7 @@ export const cancelArena = async (accts: BettingAccounts, admin: User, arenaId:
   await sendOrSimulateTransaction(await program.methods
     .cancelArena(new BN(arenaId))
     .accounts({
-      authority: admin.publicKey,
+      operator: admin.publicKey,
       globalState: await keys.getGlobalStateKey(),
       arenaState: await keys.getArenaStateKey(arenaId),
       solPythAccount: new PublicKey(Constants.SOL_PYTH_ACCOUNT)
@@ -158,25 +452,43 @@ export const cancelArena = async (accts: BettingAccounts, admin: User, arenaId:
   );
 };
 
+// [period_result, pool_vault] of the current period of each kind funded from arena fees
+export const getPrizePoolAccounts = async (poolMint: PublicKey, timestamp: BN): Promise<AccountMeta[]> => {
+  const periodConfig = await program.account.periodConfig.fetch(await keys.getPeriodConfigKey());
+  let accounts: AccountMeta[] = [];
+  for (let kind = 0; kind < periodConfig.kindCount; kind ++) {
+    const periodKind = periodConfig.kinds[kind];
+    if (periodKind.isEnabled != 1 || periodKind.poolFeeRate == 0) continue;
+    const period = timestamp.sub(periodKind.epochOffset).div(periodKind.length);
+    const periodResultKey = await keys.getPeriodResultKey(kind, period);
+    accounts.push({ isSigner: false, isWritable: false, pubkey: periodResultKey });
+    accounts.push({ isSigner: false, isWritable: true, pubkey: await getAssociatedTokenAddress(poolMint, periodResultKey, true) });
+  }
+  return accounts;
+};
+
 export const endArena = async (accts: BettingAccounts, admin: User, arenaId: number) => {
-  const treasuryAta = await getAssociatedTokenAddress(accts.bettingMint, 
-    new PublicKey(Constants.TREASURY));
+  const feeSplitKey = await keys.getFeeSplitKey();
+  const feeVaultAta = await getAssociatedTokenAddress(accts.bettingMint, feeSplitKey, true);
   await sendOrSimulateTransaction(await program.methods
     .endArena(new BN(arenaId))
     .accounts({
-      authority: admin.publicKey,
+      operator: admin.publicKey,
       globalState: await keys.getGlobalStateKey(),
       arenaState: await keys.getArenaStateKey(arenaId),
       solPythAccount: new PublicKey(Constants.SOL_PYTH_ACCOUNT),
-      treasury: Constants.TREASURY,
-      treasuryAta,
+      feeSplit: feeSplitKey,
+      periodConfig: await keys.getPeriodConfigKey(),
+      feeVaultAta,
       escrowAta: accts.escrowAta,
+      betMint: await keys.getBetMintKey(accts.bettingMint),
       tokenMint: accts.bettingMint,
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
     })
+    .remainingAccounts(await getPrizePoolAccounts(accts.bettingMint, new BN(Math.floor(Date.now() / 1000))))
     .signers([admin.keypair])
     .transaction(),
     [admin.keypair],
@@ -190,10 +502,14 @@ export const userBet = async (
   refKey: PublicKey,
   arenaId: number,
   betAmount: number,
-  betSide: boolean
+  betSide: boolean,
+  seasonId: number | null = null,
+  mint: PublicKey = accts.bettingMint
 ) => {
+  // SOL arenas take lamports straight from the wallet
+  const isNative = mint.equals(NATIVE_MINT);
   const amountInDecimal = new BN(betAmount).mul(
-    new BN(Math.pow(10, Constants.USDC_DECIMALS))
+    new BN(Math.pow(10, isNative ? 9 : Constants.USDC_DECIMALS))
   );
   const hash_str = crypto.createHash('sha256').update(
     user.publicKey.toBase58() + 
@@ -217,70 +533,28 @@ export const userBet = async (
   }
 
   let dateNow = Date.now();
-  
-  let hour = getPassedHours(dateNow);
-  let day = getPassedDays(dateNow);
-  let week = getPassedWeeks(dateNow);
   let eight_box_id = getEightBoxId(dateNow);
-
-  let hourStateKey = await keys.getUserHourStateKey(user.publicKey, hour);
-  let dayStateKey = await keys.getUserDayStateKey(user.publicKey, day);
-  let weekStateKey = await keys.getUserWeekStateKey(user.publicKey, week);
   let eightBoxStateKey = await keys.getEightBoxStateKey(user.publicKey, eight_box_id);
 
-  if (!(await fetchHourState(hourStateKey))) {
-    transaction.add(await program.methods
-      .initHourState(user.publicKey, hour)
-      .accounts({
-        payer: user.publicKey,
-        userHourState: hourStateKey,
-        systemProgram: SystemProgram.programId,
-        rent: SYSVAR_RENT_PUBKEY
-      })
-      .instruction()
-    )
-  }
-  
-  if (!(await fetchDayState(dayStateKey))) {
-    transaction.add(await program.methods
-      .initDayState(user.publicKey, day)
-      .accounts({
-        payer: user.publicKey,
-        userDayState: dayStateKey,
-        systemProgram: SystemProgram.programId,
-        rent: SYSVAR_RENT_PUBKEY
-      })
-      .instruction()
-    )
-  }
-  if (!(await fetchWeekState(weekStateKey))) {
-    transaction.add(await program.methods
-      .initWeekState(user.publicKey, week)
-      .accounts({
-        payer: user.publicKey,
-        userWeekState: weekStateKey,
-        systemProgram: SystemProgram.programId,
-        rent: SYSVAR_RENT_PUBKEY
-      })
-      .instruction()
-    )
+  // user period state and leaderboard of every enabled kind, in kind order
+  const periodConfigKey = await keys.getPeriodConfigKey();
+  const periodConfig = await program.account.periodConfig.fetch(periodConfigKey);
+  let remainingAccounts: AccountMeta[] = [];
+  for (let kind = 0; kind < periodConfig.kindCount; kind ++) {
+    const periodKind = periodConfig.kinds[kind];
+    if (periodKind.isEnabled != 1) continue;
+    const period = getPeriodIndex(dateNow, periodKind.length, periodKind.epochOffset);
+    remainingAccounts.push({ isSigner: false, isWritable: true, pubkey: await keys.getUserPeriodStateKey(kind, user.publicKey, period) });
+    if (periodKind.ranking == Constants.RANKING_PROFIT) continue;
+    remainingAccounts.push({ isSigner: false, isWritable: true, pubkey: await keys.getPeriodLeaderboardKey(kind, period) });
   }
-  if (!(await fetchEightBoxState(eightBoxStateKey))) {
-    transaction.add(await program.methods
-      .initEightBoxState(user.publicKey, eight_box_id)
-      .accounts({
-        payer: user.publicKey,
-        eightBoxState: eightBoxStateKey,
-        systemProgram: SystemProgram.programId,
-        rent: SYSVAR_RENT_PUBKEY
-      })
-      .instruction()
-    )
+  if (seasonId !== null) {
+    remainingAccounts.push(...await getSeasonAccounts(seasonId, user.publicKey));
   }
+
   transaction.add(await program.methods
     .userBet(
-      new BN(arenaId), amountInDecimal, 
-      hour, day, week, eight_box_id, 
+      new BN(arenaId), amountInDecimal,
       betSide ? 1 : 0, refKey, hash_arr
     ).accounts({
       user: user.publicKey,
@@ -288,19 +562,20 @@ export const userBet = async (
       arenaState: await keys.getArenaStateKey(arenaId),
       userState: user.userStateKey,
       userBetState: await keys.getUserBetStateKey(arenaId, user.publicKey),
-      userHourState: hourStateKey,
-      userDayState: dayStateKey,
-      userWeekState: weekStateKey,
       eightBoxState: eightBoxStateKey,
-      
-      userAta: user.bettingMintAta,
-      escrowAta: accts.escrowAta,
-      tokenMint: accts.bettingMint,
+      periodConfig: periodConfigKey,
+      userAta: isNative ? user.publicKey : user.bettingMintAta,
+      escrowAta: await getAssociatedTokenAddress(mint, await keys.getGlobalStateKey(), true),
+      betMint: await keys.getBetMintKey(mint),
+      tokenMint: mint,
+      volumeMint: accts.bettingMint,
+      solPythAccount: new PublicKey(Constants.SOL_PYTH_ACCOUNT),
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
     })
+    .remainingAccounts(remainingAccounts)
     .instruction());
   await sendOrSimulateTransaction(
     transaction,
@@ -328,7 +603,8 @@ export const claimReward = async (
   accts: BettingAccounts, 
   user: User, 
   refUser: User,
-  arenaId: number
+  arenaId: number,
+  seasonId: number | null = null
 ) => {
   const prevEscrowAmount = (await program.provider.connection.getTokenAccountBalance(
     accts.escrowAta
@@ -359,6 +635,9 @@ export const claimReward = async (
     );
   }
 
+  const userBetState = await program.account.userBetState.fetch(
+    await keys.getUserBetStateKey(arenaId, user.publicKey)
+  );
   await sendOrSimulateTransaction(await program.methods
     .claimReward(new BN(arenaId))
     .accounts({
@@ -369,17 +648,25 @@ export const claimReward = async (
       userState: user.userStateKey,
 
       userAta: user.bettingMintAta,
+      unwrapAccount: await keys.getUnwrapKey(user.publicKey),
       escrowAta: accts.escrowAta,
       
       refUserState: refUser.userStateKey,
       refUserVaultAta,
 
+      betMint: await keys.getBetMintKey(accts.bettingMint),
+      periodConfig: await keys.getPeriodConfigKey(),
       tokenMint: accts.bettingMint,
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
     })
+    .remainingAccounts([
+      // settle_win already booked the PnL of settled bets
+      ...(userBetState.isSettled ? [] : await getProfitStateAccounts(user.publicKey, userBetState.betTimestamp)),
+      ...(seasonId === null ? [] : await getSeasonAccounts(seasonId, user.publicKey)),
+    ])
     .signers([user.keypair])
     .preInstructions(instructions)
     .transaction(),
@@ -418,8 +705,10 @@ export const claimRefReward = async (
       user: user.publicKey,
       globalState: await keys.getGlobalStateKey(),
       userAta: user.bettingMintAta,
+      unwrapAccount: await keys.getUnwrapKey(user.publicKey),
       userState: user.userStateKey,
       userVaultAta,
+      betMint: await keys.getBetMintKey(accts.bettingMint),
       tokenMint: accts.bettingMint,
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
@@ -434,6 +723,26 @@ export const claimRefReward = async (
   );
 };
 
+export const triggerRefund = async (
+  accts: BettingAccounts,
+  user: User,
+  arenaId: number,
+) => {
+  await sendOrSimulateTransaction(await program.methods
+    .triggerRefund(new BN(arenaId))
+    .accounts({
+      user: user.publicKey,
+      globalState: accts.globalStateKey,
+      arenaState: await keys.getArenaStateKey(arenaId),
+      userBetState: await keys.getUserBetStateKey(arenaId, user.publicKey),
+    })
+    .signers([user.keypair])
+    .transaction(),
+    [user.keypair],
+    connection
+  );
+};
+
 export const returnBet = async (
   accts: BettingAccounts, 
   user: User,
@@ -454,9 +763,13 @@ export const returnBet = async (
       arenaState: await keys.getArenaStateKey(arenaId),
       userBetState: await keys.getUserBetStateKey(arenaId, user.publicKey),
       userAta: user.bettingMintAta,
+      unwrapAccount: await keys.getUnwrapKey(user.publicKey),
       escrowAta: accts.escrowAta,
+      betMint: await keys.getBetMintKey(accts.bettingMint),
       tokenMint: accts.bettingMint,
       tokenProgram: TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
     })
     .signers([user.keypair])
     .preInstructions(instructions)
@@ -467,157 +780,251 @@ export const returnBet = async (
 };
 
 
-export const endHour = async (accts: BettingAccounts, admin: User) => {
-  let hour = getPassedHours(Date.now());
-  let hour_sec = hour.mul(new BN(Constants.ONE_HOUR_SEC));
-  let hourStateAccounts = await program.account.hourState.all([{
-    memcmp: {
-      offset: 40,
-      bytes: bs58.encode(hour_sec.toArrayLike(Array, "le", 8))
-    }
-  }]);
-  hourStateAccounts.sort((a, b) => b.account.betAmount.cmp(a.account.betAmount));
-  //let hourStateAccounts = await program.account.hourState.all();
-  console.log("hourStateAccounts =", hourStateAccounts.map(acc => acc.account.betAmount.toString()));
-  let tiers = [];
-  let tier_dist = [1, 2, 3, 5, 10];
-  let distIdx = 0;
-  let rewardPerTier = [428, 749, 535, 107, 42];
-  let winners = 10;
-  let winner_cnt = 0;
-
-  if (hourStateAccounts.length > 0) {
-    for (let acc of hourStateAccounts) {
-      winner_cnt ++;
-      if (winner_cnt == tier_dist[distIdx]) {
-        distIdx ++;
-        tiers.push(acc.account.betAmount);
-      }
-      if (winner_cnt == winners) break;
-    }
-    if (hourStateAccounts.length < winners) {
-      while (distIdx ++ < tier_dist.length) {
-        tiers.push(hourStateAccounts[hourStateAccounts.length - 1].account.betAmount);
-      }
-    }
+// user period states of the profit ranked kinds at the time of a bet, for claim_reward and settle_loss
+export const getProfitStateAccounts = async (userKey: PublicKey, betTimestamp: BN): Promise<AccountMeta[]> => {
+  const periodConfig = await program.account.periodConfig.fetch(await keys.getPeriodConfigKey());
+  let accounts: AccountMeta[] = [];
+  for (let kind = 0; kind < periodConfig.kindCount; kind ++) {
+    const periodKind = periodConfig.kinds[kind];
+    if (periodKind.isEnabled != 1 || periodKind.ranking != Constants.RANKING_PROFIT) continue;
+    const period = betTimestamp.sub(periodKind.epochOffset).div(periodKind.length);
+    accounts.push({ isSigner: false, isWritable: true, pubkey: await keys.getUserPeriodStateKey(kind, userKey, period) });
   }
-  console.log("tiers =", tiers.map(v => v.toString()));
+  return accounts;
+};
+
+// books the losing bets of an arena against their users' PnL
+export const settleLoss = async (
+  settler: User,
+  userKey: PublicKey,
+  arenaId: number
+) => {
+  const userBetStateKey = await keys.getUserBetStateKey(arenaId, userKey);
+  const userBetState = await program.account.userBetState.fetch(userBetStateKey);
+  await sendOrSimulateTransaction(await program.methods
+    .settleLoss(new BN(arenaId))
+    .accounts({
+      settler: settler.publicKey,
+      globalState: await keys.getGlobalStateKey(),
+      arenaState: await keys.getArenaStateKey(arenaId),
+      user: userKey,
+      userBetState: userBetStateKey,
+      periodConfig: await keys.getPeriodConfigKey(),
+    })
+    .remainingAccounts(await getProfitStateAccounts(userKey, userBetState.betTimestamp))
+    .signers([settler.keypair])
+    .transaction(),
+    [settler.keypair],
+    connection
+  );
+};
+
+export const settleWin = async (
+  settler: User,
+  userKey: PublicKey,
+  arenaId: number
+) => {
+  const userBetStateKey = await keys.getUserBetStateKey(arenaId, userKey);
+  const userBetState = await program.account.userBetState.fetch(userBetStateKey);
+  await sendOrSimulateTransaction(await program.methods
+    .settleWin(new BN(arenaId))
+    .accounts({
+      settler: settler.publicKey,
+      globalState: await keys.getGlobalStateKey(),
+      arenaState: await keys.getArenaStateKey(arenaId),
+      user: userKey,
+      userBetState: userBetStateKey,
+      periodConfig: await keys.getPeriodConfigKey(),
+    })
+    .remainingAccounts(await getProfitStateAccounts(userKey, userBetState.betTimestamp))
+    .signers([settler.keypair])
+    .transaction(),
+    [settler.keypair],
+    connection
+  );
+};
 
+// season, user season state and season leaderboard, counts bets and wins towards a season
+export const getSeasonAccounts = async (seasonId: number, userKey: PublicKey): Promise<AccountMeta[]> => {
+  return [
+    { isSigner: false, isWritable: false, pubkey: await keys.getSeasonKey(seasonId) },
+    { isSigner: false, isWritable: true, pubkey: await keys.getUserSeasonStateKey(seasonId, userKey) },
+    { isSigner: false, isWritable: true, pubkey: await keys.getSeasonLeaderboardKey(seasonId) },
+  ];
+};
+
+export const createSeason = async (
+  admin: User,
+  seasonId: number,
+  startTime: number,
+  endTime: number,
+  winBonus: number,
+  name: string,
+  uri: string,
+  claimWindow: number = Constants.ONE_WEEK_SEC * 4
+) => {
   await sendOrSimulateTransaction(await program.methods
-    .endHour(hour, tiers.map((v) => new BN(v)), rewardPerTier.map(v => new BN(v)))
+    .createSeason(new BN(seasonId), new BN(startTime), new BN(endTime), new BN(winBonus), new BN(claimWindow), name, uri)
     .accounts({
       authority: admin.publicKey,
       globalState: await keys.getGlobalStateKey(),
-      hourResult: await keys.getHourResultKey(hour),
+      season: await keys.getSeasonKey(seasonId),
+      seasonLeaderboard: await keys.getSeasonLeaderboardKey(seasonId),
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .signers([admin.keypair])
+    .transaction(),
+    [admin.keypair],
+    connection
+  );
+};
+
+export const endSeason = async (
+  accts: BettingAccounts,
+  admin: User,
+  seasonId: number,
+  rewards: number[]
+) => {
+  const globalStateKey = await keys.getGlobalStateKey();
+  const seasonKey = await keys.getSeasonKey(seasonId);
+  await sendOrSimulateTransaction(await program.methods
+    .endSeason(new BN(seasonId), rewards.map(v => new BN(v)))
+    .accounts({
+      ranker: admin.publicKey,
+      globalState: globalStateKey,
+      season: seasonKey,
+      seasonLeaderboard: await keys.getSeasonLeaderboardKey(seasonId),
+      feelVaultAta: await getAssociatedTokenAddress(accts.rankMint, globalStateKey, true),
+      seasonRewardVault: await getAssociatedTokenAddress(accts.rankMint, seasonKey, true),
+      rankMint: accts.rankMint,
+      feelTokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .signers([admin.keypair])
+    .transaction(),
+    [admin.keypair],
+    connection
+  );
+};
+
+export const claimSeasonTrophy = async (
+  accts: BettingAccounts,
+  user: User,
+  seasonId: number
+) => {
+  const globalStateKey = await keys.getGlobalStateKey();
+  let signers: Signer[] = [];
+  let instructions: TransactionInstruction[] = [];
+  let remainingAccounts: AccountMeta[] = [];
+  await prepareMintNft(user, remainingAccounts, instructions, signers);
+
+  let transaction = new Transaction();
+  transaction.add(...instructions);
+  transaction.add(await program.methods
+    .claimSeasonTrophy(new BN(seasonId))
+    .accounts({
+      user: user.publicKey,
+      globalState: globalStateKey,
+      season: await keys.getSeasonKey(seasonId),
+      seasonRewardVault: await getAssociatedTokenAddress(accts.rankMint, await keys.getSeasonKey(seasonId), true),
+      userFeelAta: await getAssociatedTokenAddress(accts.rankMint, user.publicKey),
+      nftCreator: remainingAccounts[0].pubkey,
+      nftMint: remainingAccounts[1].pubkey,
+      userNftAta: remainingAccounts[2].pubkey,
+      nftMetadata: remainingAccounts[3].pubkey,
+      nftEdition: remainingAccounts[4].pubkey,
+      rankMint: accts.rankMint,
+      feelTokenProgram: TOKEN_PROGRAM_ID,
+      tokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+      tokenMetadataProgram: new PublicKey(Constants.MetadataProgramId),
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
     })
-    .signers([admin.keypair])
-    .transaction(),
-    [admin.keypair],
-    connection
+    .signers([user.keypair])
+    .instruction()
+  );
+  await sendOrSimulateTransaction(
+    transaction,
+    [user.keypair, ...signers],
+    connection,
+    false
   );
 };
 
-
-export const endDay = async (accts: BettingAccounts, admin: User) => {
-  let day = getPassedDays(Date.now());
-  let day_sec = day.mul(new BN(Constants.ONE_DAY_SEC));
-  let dayStateAccounts = await program.account.dayState.all([{
-    memcmp: {
-      offset: 40,
-      bytes: bs58.encode(day_sec.toArrayLike(Array, "le", 8))
-    }
-  }]);
-  dayStateAccounts.sort((a, b) => b.account.betAmount.cmp(a.account.betAmount));
-  console.log("dayStateAccounts =", dayStateAccounts.map(acc => acc.account.betAmount.toString()));
-  let tiers = [];
-  let tier_dist = [1, 2, 3, 5, 10, 25, 50];
-  let distIdx = 0;
-  let rewardPerTier = [10273, 15410, 10273, 2568, 1027, 171, 102];
-  let winners = 50;
-  let winner_cnt = 0;
-  
-  if (dayStateAccounts.length > 0) {
-    for (let acc of dayStateAccounts) {
-      winner_cnt ++;
-      if (winner_cnt == tier_dist[distIdx]) {
-        distIdx ++;
-        tiers.push(acc.account.betAmount);
-      }
-      if (winner_cnt == winners) break;
-    }
-    if (dayStateAccounts.length < winners) {
-      while (distIdx ++ < tier_dist.length) {
-        tiers.push(dayStateAccounts[dayStateAccounts.length - 1].account.betAmount);
-      }
-    }
+// entries of a period leaderboard, decoded past the zero copy header
+export const fetchPeriodLeaderboard = async (kind: number, period: BN) => {
+  const info = await connection.getAccountInfo(await keys.getPeriodLeaderboardKey(kind, period));
+  if (info === null) return [];
+  const header = 8 + 64;
+  const count = new BN(info.data.subarray(8 + 16, 8 + 24), "le").toNumber();
+  let entries = [];
+  for (let i = 0; i < count; i ++) {
+    const offset = header + i * 40;
+    entries.push({
+      user: new PublicKey(info.data.subarray(offset, offset + 32)),
+      volume: new BN(info.data.subarray(offset + 32, offset + 40), "le"),
+    });
   }
-  console.log("tiers =", tiers.map(v => v.toString()));
+  return entries;
+};
 
+// publishes a finished period, tiers are taken from its on-chain leaderboard
+// anyone can close a board, `closer` only pays the transaction fee
+export const closePeriodLeaderboard = async (
+  closer: User,
+  payer: PublicKey,
+  kind: number,
+  period: BN
+) => {
   await sendOrSimulateTransaction(await program.methods
-    .endDay(day, tiers.map((v) => new BN(v)), rewardPerTier.map(v => new BN(v)))
+    .closePeriodLeaderboard()
     .accounts({
-      authority: admin.publicKey,
-      globalState: await keys.getGlobalStateKey(),
-      dayResult: await keys.getDayResultKey(day),
-      systemProgram: SystemProgram.programId,
-      rent: SYSVAR_RENT_PUBKEY,
+      payer,
+      periodLeaderboard: await keys.getPeriodLeaderboardKey(kind, period),
+      periodResult: await keys.getPeriodResultKey(kind, period),
+      periodConfig: await keys.getPeriodConfigKey(),
     })
-    .signers([admin.keypair])
     .transaction(),
-    [admin.keypair],
+    [closer.keypair],
     connection
   );
 };
 
-
-export const endWeek = async (accts: BettingAccounts, admin: User) => {
-  let week = getPassedWeeks(Date.now());
-  let week_sec = week.mul(new BN(Constants.ONE_WEEK_SEC));
-  let weekStateAccounts = await program.account.weekState.all([{
-    memcmp: {
-      offset: 40,
-      bytes: bs58.encode(week_sec.toArrayLike(Array, "le", 8))
-    }
-  }]);
-
-  //let weekStateAccounts = await program.account.weekState.all();
-
-  weekStateAccounts.sort((a, b) => b.account.betAmount.cmp(a.account.betAmount));
-  console.log("weekStateAccounts betAmount =", weekStateAccounts.map(acc => acc.account.betAmount.toString()));
-  console.log("weekStateAccounts startTime =", weekStateAccounts.map(acc => acc.account.startTime.toString()));
-  let tiers = [];
-  let tier_dist = [1, 2, 3, 5, 10, 25, 50, 100, 250];
-  let distIdx = 0;
-  let rewardPerTier = [32363, 43150, 21575, 10787, 4315, 1438, 863, 431, 107];
-  let winners = 250;
-  let winner_cnt = 0;
-
-  if (weekStateAccounts.length > 0) {
-    for (let acc of weekStateAccounts) {
-      winner_cnt ++;
-      if (winner_cnt == tier_dist[distIdx]) {
-        distIdx ++;
-        tiers.push(acc.account.betAmount);
-      }
-      if (winner_cnt == winners) break;
-    }
-    if (weekStateAccounts.length < winners) {
-      while (distIdx ++ < tier_dist.length) {
-        tiers.push(weekStateAccounts[weekStateAccounts.length - 1].account.betAmount);
-      }
-    }
-  }
-
-  console.log("tiers =", tiers.map(v => v.toString()));
+export const endPeriod = async (
+  accts: BettingAccounts,
+  admin: User,
+  kind: number,
+  rewardPerTier: number[],
+  period: BN | null = null,
+  merkleRoot: IdlTypes<Betting>["MerkleRoot"] | null = null
+) => {
+  if (period === null) period = (await getCurrentPeriod(kind)).subn(1);
+  const leaderboard = await fetchPeriodLeaderboard(kind, period);
+  console.log("periodLeaderboard =", leaderboard
+    .map(entry => entry.user.toBase58() + " " + entry.volume.toString()));
+  const periodResultKey = await keys.getPeriodResultKey(kind, period);
 
   await sendOrSimulateTransaction(await program.methods
-    .endWeek(week, tiers.map((v) => new BN(v)), rewardPerTier.map(v => new BN(v)))
+    .endPeriod(kind, period, rewardPerTier.map(v => new BN(v)), merkleRoot)
     .accounts({
-      authority: admin.publicKey,
+      ranker: admin.publicKey,
       globalState: await keys.getGlobalStateKey(),
-      weekResult: await keys.getWeekResultKey(week),
+      periodConfig: await keys.getPeriodConfigKey(),
+      feelVaultAta: await getAssociatedTokenAddress(accts.rankMint, await keys.getGlobalStateKey(), true),
+      periodResult: periodResultKey,
+      periodRewardVault: await getAssociatedTokenAddress(accts.rankMint, periodResultKey, true),
+      periodLeaderboard: await keys.getPeriodLeaderboardKey(kind, period),
+      rankClaims: await keys.getRankClaimsKey(periodResultKey),
+      poolVault: await getAssociatedTokenAddress(accts.bettingMint, periodResultKey, true),
+      rankMint: accts.rankMint,
+      feelTokenProgram: TOKEN_PROGRAM_ID,
+      poolMint: accts.bettingMint,
+      poolTokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
     })
@@ -628,56 +1035,81 @@ export const endWeek = async (accts: BettingAccounts, admin: User) => {
   );
 };
 
-export const claimHourRankReward = async (
+// standing of a user in a published result, proof results are unranked without the user's proof
+export const getRank = async (
+  userKey: PublicKey,
+  kind: number,
+  period: BN,
+  proof: IdlTypes<Betting>["RankProof"] | null = null
+): Promise<IdlTypes<Betting>["RankView"]> => {
+  const periodResultKey = await keys.getPeriodResultKey(kind, period);
+  return await program.methods
+    .getRank(proof)
+    .accounts({
+      user: userKey,
+      userPeriodState: await keys.getUserPeriodStateKey(kind, userKey, period),
+      periodResult: periodResultKey,
+      periodLeaderboard: await keys.getPeriodLeaderboardKey(kind, period),
+      rankClaims: await keys.getRankClaimsKey(periodResultKey),
+    }).view();
+};
+
+export const claimRankReward = async (
   accts: BettingAccounts, 
   user: User,
+  kind: number,
+  period: BN | null = null,
+  proof: IdlTypes<Betting>["RankProof"] | null = null
 ) => {
-  let hour = getPassedHours(Date.now());
+  if (period === null) period = (await getCurrentPeriod(kind)).subn(1);
   
   const globalStateKey = await keys.getGlobalStateKey();
   
-  const feelVaultAta = await getAssociatedTokenAddress(
-    accts.rankMint,
-    globalStateKey,
-    true
-  );
-
   const userFeelAta = await getAssociatedTokenAddress(
     accts.rankMint,
     user.publicKey
   );
   
-  let hourStateKey = await keys.getUserHourStateKey(user.publicKey, hour);
-  let hourResultKey = await keys.getHourResultKey(hour);
+  let periodStateKey = await keys.getUserPeriodStateKey(kind, user.publicKey, period);
+  let periodResultKey = await keys.getPeriodResultKey(kind, period);
 
-  let rank = await program.methods
-    .getHourRank()
-    .accounts({
-      userHourState: hourStateKey,
-      hourResult: hourResultKey,
-    }).view();
+  const rank = await getRank(user.publicKey, kind, period, proof);
   console.log("rank =", rank);
 
-  let remainingAccounts: AccountMeta[] = [];
-  let transaction = new Transaction();
+  // the prize of the tier is minted along with the reward
+  // vesting results lock the reward in a vesting account owned vault
+  const vestingKey = await keys.getVestingKey(periodResultKey, user.publicKey);
 
+  let transaction = new Transaction();
+  let remainingAccounts: AccountMeta[] = [];
   let instructions: TransactionInstruction[] = [];
   let signers: Signer[] = [];
-  if (rank == 1) {    
-    await prepareMintBundle(user, remainingAccounts, instructions, signers, 1);
-    transaction.add(...instructions);
+  if (rank.isRanked && rank.prize == Constants.PRIZE_NFT) {
+    await prepareMintNft(user, remainingAccounts, instructions, signers);
+  } else if (rank.isRanked && rank.prize == Constants.PRIZE_BUNDLE) {
+    await prepareMintBundle(user, remainingAccounts, instructions, signers);
   }
+  transaction.add(...instructions);
 
   transaction.add(await program.methods
-    .claimHourRankReward(hour)
+    .claimRankReward(kind, period, proof)
     .accounts({
       user: user.publicKey,
       globalState: globalStateKey,
-      feelVaultAta,
-      userHourState: hourStateKey,
-      hourResult: await keys.getHourResultKey(hour),
+      userPeriodState: periodStateKey,
+      periodResult: periodResultKey,
+      periodRewardVault: await getAssociatedTokenAddress(accts.rankMint, periodResultKey, true),
+      periodLeaderboard: await keys.getPeriodLeaderboardKey(kind, period),
+      rankClaims: await keys.getRankClaimsKey(periodResultKey),
+      vesting: vestingKey,
+      vestingVault: await getAssociatedTokenAddress(accts.rankMint, vestingKey, true),
+      poolVault: await getAssociatedTokenAddress(accts.bettingMint, periodResultKey, true),
+      userPoolAta: await getAssociatedTokenAddress(accts.bettingMint, user.publicKey),
       userFeelAta,
       rankMint: accts.rankMint,
+      feelTokenProgram: TOKEN_PROGRAM_ID,
+      poolMint: accts.bettingMint,
+      poolTokenProgram: TOKEN_PROGRAM_ID,
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       tokenMetadataProgram: new PublicKey(Constants.MetadataProgramId),
@@ -686,8 +1118,8 @@ export const claimHourRankReward = async (
     })
     .remainingAccounts([...remainingAccounts])
     .signers([user.keypair])
-    .instruction());
-
+    .instruction()
+  );  
   await sendOrSimulateTransaction(
     transaction,
     [user.keypair, ...signers],
@@ -696,137 +1128,142 @@ export const claimHourRankReward = async (
   );
 };
 
-
-export const claimDayRankReward = async (
-  accts: BettingAccounts, 
+// hour, day or week reward of the baseline program, `prize` is the prize of the state's tier
+export const claimLegacyRankReward = async (
+  accts: BettingAccounts,
   user: User,
+  legacyKind: number,
+  period: BN,
+  prize: number = Constants.PRIZE_NONE
 ) => {
-  let day = getPassedDays(Date.now());
-  
   const globalStateKey = await keys.getGlobalStateKey();
-  
-  const feelVaultAta = await getAssociatedTokenAddress(
-    accts.rankMint,
-    globalStateKey,
-    true
-  );
-
-  const userFeelAta = await getAssociatedTokenAddress(
-    accts.rankMint,
-    user.publicKey
-  );
-  
-  let dayStateKey = await keys.getUserDayStateKey(user.publicKey, day);
-  let dayResultKey = await keys.getDayResultKey(day);
-
-  let rank = await program.methods
-    .getDayRank()
-    .accounts({
-      userDayState: dayStateKey,
-      dayResult: dayResultKey,
-    }).view();
-  console.log("rank =", rank);
-  
   let remainingAccounts: AccountMeta[] = [];
-  let transaction = new Transaction();
-  
   let instructions: TransactionInstruction[] = [];
   let signers: Signer[] = [];
-  if (rank == 1) {    
-    await prepareMintBundle(user, remainingAccounts, instructions, signers, 1);
-    transaction.add(...instructions);
+  if (prize == Constants.PRIZE_NFT) {
+    await prepareMintNft(user, remainingAccounts, instructions, signers);
+  } else if (prize == Constants.PRIZE_BUNDLE) {
+    await prepareMintBundle(user, remainingAccounts, instructions, signers);
   }
-
+  const transaction = new Transaction();
+  transaction.add(...instructions);
   transaction.add(await program.methods
-    .claimDayRankReward(day)
+    .claimLegacyRankReward(legacyKind, period)
     .accounts({
       user: user.publicKey,
       globalState: globalStateKey,
-      feelVaultAta,
-      userDayState: dayStateKey,
-      dayResult: dayResultKey,
-      userFeelAta,
+      feelVaultAta: await getAssociatedTokenAddress(accts.rankMint, globalStateKey, true),
+      legacyState: await keys.getLegacyStateKey(legacyKind, user.publicKey, period),
+      legacyResult: await keys.getLegacyResultKey(legacyKind, period),
+      userFeelAta: await getAssociatedTokenAddress(accts.rankMint, user.publicKey),
       rankMint: accts.rankMint,
+      feelTokenProgram: TOKEN_PROGRAM_ID,
       tokenProgram: TOKEN_PROGRAM_ID,
       associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       tokenMetadataProgram: new PublicKey(Constants.MetadataProgramId),
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
     })
-    .remainingAccounts([...remainingAccounts])
-    .signers([user.keypair])
+    .remainingAccounts(remainingAccounts)
     .instruction()
   );
   await sendOrSimulateTransaction(
     transaction,
     [user.keypair, ...signers],
-    connection,
-    false
+    connection
   );
 };
 
 
-export const claimWeekRankReward = async (
-  accts: BettingAccounts, 
-  user: User,
+
+// thresholds in USDC with 6 decimals, bundles 1, 4, 5 and 6 as before the config
+export const DEFAULT_EIGHT_BOX_PRIZES = [
+  { volume: new BN(20_000_000), kind: Constants.EIGHT_BOX_BUNDLE, id: 0, amount: new BN(0) },
+  { volume: new BN(100_000_000), kind: Constants.EIGHT_BOX_BUNDLE, id: 3, amount: new BN(0) },
+  { volume: new BN(400_000_000), kind: Constants.EIGHT_BOX_BUNDLE, id: 4, amount: new BN(0) },
+  { volume: new BN(1000_000_000), kind: Constants.EIGHT_BOX_BUNDLE, id: 5, amount: new BN(0) },
+];
+
+export const setEightBoxPrizes = async (
+  admin: User,
+  prizes: IdlTypes<Betting>["EightBoxPrize"][] = DEFAULT_EIGHT_BOX_PRIZES,
+  claimWindow: number = Constants.ONE_WEEK_SEC
 ) => {
-  let week = getPassedWeeks(Date.now());
-  
-  const globalStateKey = await keys.getGlobalStateKey();
-  
-  const feelVaultAta = await getAssociatedTokenAddress(
-    accts.rankMint,
-    globalStateKey,
-    true
+  await sendOrSimulateTransaction(await program.methods
+    .setEightBoxPrizes(prizes, new BN(claimWindow))
+    .accounts({
+      authority: admin.publicKey,
+      globalState: await keys.getGlobalStateKey(),
+      eightBoxConfig: await keys.getEightBoxConfigKey(),
+      systemProgram: SystemProgram.programId,
+      rent: SYSVAR_RENT_PUBKEY,
+    })
+    .signers([admin.keypair])
+    .transaction(),
+    [admin.keypair],
+    connection
   );
+};
 
-  const userFeelAta = await getAssociatedTokenAddress(
-    accts.rankMint,
-    user.publicKey
-  );
-  
-  let weekStateKey = await keys.getUserWeekStateKey(user.publicKey, week);
-  let weekResultKey = await keys.getWeekResultKey(week);
+// remaining accounts of a prize: a bundle mint, the fragment mint and ata, nothing for FEEL
+const prepareEightBoxPrize = async (
+  user: User,
+  prize: IdlTypes<Betting>["EightBoxPrize"],
+  remainingAccounts: AccountMeta[],
+  instructions: TransactionInstruction[],
+  signers: Signer[]
+) => {
+  if (prize.kind == Constants.EIGHT_BOX_BUNDLE) {
+    await prepareMintBundle(user, remainingAccounts, instructions, signers, 1);
+  } else if (prize.kind == Constants.EIGHT_BOX_FRAGMENT) {
+    const fragmentMint = await keys.getFragmentMintKey(prize.id);
+    remainingAccounts.push({ isSigner: false, isWritable: true, pubkey: fragmentMint });
+    remainingAccounts.push({ isSigner: false, isWritable: true, pubkey: await getAssociatedTokenAddress(fragmentMint, user.publicKey) });
+  }
+};
 
-  let rank = await program.methods
-    .getWeekRank()
-    .accounts({
-      userWeekState: weekStateKey,
-      weekResult: weekResultKey,
-    }).view();
-  console.log("rank =", rank);
+const getClaimEightBoxAccounts = async (accts: BettingAccounts, user: User, eight_box_id: BN) => {
+  const globalStateKey = await keys.getGlobalStateKey();
+  return {
+    user: user.publicKey,
+    globalState: globalStateKey,
+    eightBoxConfig: await keys.getEightBoxConfigKey(),
+    eightBoxState: await keys.getEightBoxStateKey(user.publicKey, eight_box_id),
+    feelVaultAta: await getAssociatedTokenAddress(accts.rankMint, globalStateKey, true),
+    userFeelAta: await getAssociatedTokenAddress(accts.rankMint, user.publicKey),
+    rankMint: accts.rankMint,
+    feelTokenProgram: TOKEN_PROGRAM_ID,
+    tokenProgram: TOKEN_PROGRAM_ID,
+    associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
+    tokenMetadataProgram: new PublicKey(Constants.MetadataProgramId),
+    systemProgram: SystemProgram.programId,
+    rent: SYSVAR_RENT_PUBKEY,
+  };
+};
+
+// boxes stay claimable for a week after their 8 hour window
+export const claimEightBoxReward = async (
+  accts: BettingAccounts, 
+  user: User,
+  prize_id: number,
+  eight_box_id: BN = getEightBoxId(Date.now())
+) => {
+  const eightBoxConfig = await program.account.eightBoxConfig.fetch(await keys.getEightBoxConfigKey());
 
-  let transaction = new Transaction();
   let remainingAccounts: AccountMeta[] = [];
-  let signers = [];
   let instructions = [];
-  if (rank == 1) {
-    await prepareMintNft(user, remainingAccounts, instructions, signers);
-  } else if (rank == 2 || rank == 3) {
-    await prepareMintBundle(user, remainingAccounts, instructions, signers);
-  }
+  let signers = [];
+  let transaction = new Transaction();
+  // prize_id starts from 0
+  await prepareEightBoxPrize(user, eightBoxConfig.prizes[prize_id], remainingAccounts, instructions, signers);
   transaction.add(...instructions);
-
   transaction.add(await program.methods
-    .claimWeekRankReward(week)
-    .accounts({
-      user: user.publicKey,
-      globalState: globalStateKey,
-      feelVaultAta,
-      userWeekState: weekStateKey,
-      weekResult: weekResultKey,
-      userFeelAta,
-      rankMint: accts.rankMint,
-      tokenProgram: TOKEN_PROGRAM_ID,
-      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-      tokenMetadataProgram: new PublicKey(Constants.MetadataProgramId),
-      systemProgram: SystemProgram.programId,
-      rent: SYSVAR_RENT_PUBKEY,
-    })
+    .claimEightBox(eight_box_id, prize_id)
+    .accounts(await getClaimEightBoxAccounts(accts, user, eight_box_id))
     .remainingAccounts([...remainingAccounts])
     .signers([user.keypair])
-    .instruction()
-  );  
+    .instruction());
+
   await sendOrSimulateTransaction(
     transaction,
     [user.keypair, ...signers],
@@ -835,36 +1272,28 @@ export const claimWeekRankReward = async (
   );
 };
 
-
-
-export const claimEightBoxReward = async (
+// claims every unlocked prize of the box that is not claimed yet
+export const claimAllEightBoxRewards = async (
   accts: BettingAccounts, 
   user: User,
-  prize_id: number
+  eight_box_id: BN = getEightBoxId(Date.now())
 ) => {
-  const globalStateKey = await keys.getGlobalStateKey();
-  let eight_box_id = getEightBoxId(Date.now());
-  const boxStateKey = await keys.getEightBoxStateKey(user.publicKey, eight_box_id)
+  const eightBoxConfig = await program.account.eightBoxConfig.fetch(await keys.getEightBoxConfigKey());
+  const boxState = await program.account.eightBoxState.fetch(await keys.getEightBoxStateKey(user.publicKey, eight_box_id));
 
   let remainingAccounts: AccountMeta[] = [];
   let instructions = [];
   let signers = [];
   let transaction = new Transaction();
-  // prize_id starts from 0
-  await prepareMintBundle(user, remainingAccounts, instructions, signers, 1);
+  for (let prize_id = 0; prize_id < eightBoxConfig.prizeCount; prize_id ++) {
+    const prize = eightBoxConfig.prizes[prize_id];
+    if (boxState.betAmount.lt(prize.volume) || boxState.claimedStatus.testn(prize_id)) continue;
+    await prepareEightBoxPrize(user, prize, remainingAccounts, instructions, signers);
+  }
   transaction.add(...instructions);
   transaction.add(await program.methods
-    .claimEightBox(eight_box_id, prize_id)
-    .accounts({
-      user: user.publicKey,
-      globalState: globalStateKey,
-      eightBoxState: boxStateKey,
-      tokenProgram: TOKEN_PROGRAM_ID,
-      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
-      tokenMetadataProgram: new PublicKey(Constants.MetadataProgramId),
-      systemProgram: SystemProgram.programId,
-      rent: SYSVAR_RENT_PUBKEY,
-    })
+    .claimAllEightBox(eight_box_id)
+    .accounts(await getClaimEightBoxAccounts(accts, user, eight_box_id))
     .remainingAccounts([...remainingAccounts])
     .signers([user.keypair])
     .instruction());
@@ -998,6 +1427,7 @@ export const buyBundle = async (accts: BettingAccounts, user: User, bundleId: nu
       feelTreasuryAta,
       userFeelAta,
       feelMint: accts.rankMint,
+      feelTokenProgram: TOKEN_PROGRAM_ID,
       tokenMetadataProgram: new PublicKey(Constants.MetadataProgramId),
       tokenProgram: TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
@@ -1062,6 +1492,7 @@ export const buyNft = async (accts: BettingAccounts, user: User) => {
       feelTreasuryAta,
       userFeelAta,
       feelMint: accts.rankMint,
+      feelTokenProgram: TOKEN_PROGRAM_ID,
       tokenMetadataProgram: new PublicKey(Constants.MetadataProgramId),
       tokenProgram: TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
@@ -1092,7 +1523,7 @@ export const mintFragment = async (
   transaction.add(await program.methods
     .mintFragment(fragmentNo)
     .accounts({
-      authority: admin.publicKey,
+      minter: admin.publicKey,
       globalState: globalStateKey,
       mint: mintKey,
       userAta: ataKey,
@@ -1207,6 +1638,44 @@ export const buildNFT = async (
   );
 };
 
+export const migrateAccount = async (
+  payer: User,
+  targetAccount: PublicKey
+) => {
+  await sendOrSimulateTransaction(await program.methods
+    .migrateAccount()
+    .accounts({
+      payer: payer.publicKey,
+      targetAccount,
+      globalState: await keys.getGlobalStateKey(),
+      systemProgram: SystemProgram.programId,
+    })
+    .signers([payer.keypair])
+    .transaction(),
+    [payer.keypair],
+    connection
+  );
+};
+
+export const closeLegacyResult = async (
+  treasurer: User,
+  legacyKind: number,
+  period: BN
+) => {
+  await sendOrSimulateTransaction(await program.methods
+    .closeLegacyResult(legacyKind, period)
+    .accounts({
+      treasurer: treasurer.publicKey,
+      globalState: await keys.getGlobalStateKey(),
+      legacyResult: await keys.getLegacyResultKey(legacyKind, period),
+    })
+    .signers([treasurer.keypair])
+    .transaction(),
+    [treasurer.keypair],
+    connection
+  );
+};
+
 export const closeArenaState = async (
   admin: User,
   arenaId: number
@@ -1215,7 +1684,7 @@ export const closeArenaState = async (
   await sendOrSimulateTransaction(await program.methods
     .closeArenaState()
     .accounts({
-      authority: admin.publicKey,
+      treasurer: admin.publicKey,
       globalState: await keys.getGlobalStateKey(),
       arenaState: await keys.getArenaStateKey(arenaId),
       systemProgram: SystemProgram.programId,
@@ -1232,18 +1701,31 @@ export const closeArenaState = async (
 }
 
 
-export const closeDayResult = async (
+export const closePeriodResult = async (
+  accts: BettingAccounts,
   admin: User,
-  day: number
+  kind: number,
+  period: BN
 ) => {
+  const periodResultKey = await keys.getPeriodResultKey(kind, period);
   let preBal = (await connection.getBalance(admin.publicKey));
 
   await sendOrSimulateTransaction(await program.methods
-    .closeDayResult()
+    .closePeriodResult()
     .accounts({
-      authority: admin.publicKey,
+      treasurer: admin.publicKey,
       globalState: await keys.getGlobalStateKey(),
-      dayResult: await keys.getDayResultKey(day),
+      feelVaultAta: await getAssociatedTokenAddress(accts.rankMint, await keys.getGlobalStateKey(), true),
+      periodResult: periodResultKey,
+      periodRewardVault: await getAssociatedTokenAddress(accts.rankMint, periodResultKey, true),
+      rankClaims: await keys.getRankClaimsKey(periodResultKey),
+      feeSplit: await keys.getFeeSplitKey(),
+      feeVaultAta: await getAssociatedTokenAddress(accts.bettingMint, await keys.getFeeSplitKey(), true),
+      poolVault: await getAssociatedTokenAddress(accts.bettingMint, periodResultKey, true),
+      rankMint: accts.rankMint,
+      feelTokenProgram: TOKEN_PROGRAM_ID,
+      poolMint: accts.bettingMint,
+      poolTokenProgram: TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
     })
@@ -1258,59 +1740,85 @@ export const closeDayResult = async (
 
 }
 
-export const closeHourResult = async (
-  admin: User,
-  hour: number
+export const closePeriodState = async (
+  closer: User,
+  userKey: PublicKey,
+  kind: number,
+  period: BN
 ) => {
+  await sendOrSimulateTransaction(await program.methods
+    .closePeriodState()
+    .accounts({
+      user: userKey,
+      userPeriodState: await keys.getUserPeriodStateKey(kind, userKey, period),
+      periodResult: await keys.getPeriodResultKey(kind, period),
+      periodConfig: await keys.getPeriodConfigKey(),
+    })
+    .signers([closer.keypair])
+    .transaction(),
+    [closer.keypair],
+    connection
+  );
+};
 
-  let preBal = (await connection.getBalance(admin.publicKey));
-  
+export const closeSeason = async (
+  accts: BettingAccounts,
+  admin: User,
+  seasonId: number,
+  leaderboardPayer: PublicKey = admin.publicKey
+) => {
+  const globalStateKey = await keys.getGlobalStateKey();
+  const seasonKey = await keys.getSeasonKey(seasonId);
   await sendOrSimulateTransaction(await program.methods
-    .closeHourResult()
+    .closeSeason()
     .accounts({
-      authority: admin.publicKey,
-      globalState: await keys.getGlobalStateKey(),
-      hourResult: await keys.getHourResultKey(hour),
+      treasurer: admin.publicKey,
+      globalState: globalStateKey,
+      feelVaultAta: await getAssociatedTokenAddress(accts.rankMint, globalStateKey, true),
+      season: seasonKey,
+      seasonRewardVault: await getAssociatedTokenAddress(accts.rankMint, seasonKey, true),
+      seasonLeaderboard: await keys.getSeasonLeaderboardKey(seasonId),
+      leaderboardPayer,
+      rankMint: accts.rankMint,
+      feelTokenProgram: TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
-      rent: SYSVAR_RENT_PUBKEY,
     })
     .signers([admin.keypair])
     .transaction(),
     [admin.keypair],
     connection
   );
+};
 
-  let pastBal = (await connection.getBalance(admin.publicKey));
-  console.log("Sol change: ", (pastBal - preBal) / LAMPORTS_PER_SOL);
-
-}
-
-export const closeWeekResult = async (
-  admin: User,
-  week: number
+export const withdrawVested = async (
+  accts: BettingAccounts,
+  user: User,
+  kind: number,
+  period: BN
 ) => {
-  
-  let preBal = (await connection.getBalance(admin.publicKey));
-  
+  const periodResultKey = await keys.getPeriodResultKey(kind, period);
+  const vestingKey = await keys.getVestingKey(periodResultKey, user.publicKey);
+
   await sendOrSimulateTransaction(await program.methods
-    .closeWeekResult()
+    .withdrawVested()
     .accounts({
-      authority: admin.publicKey,
+      user: user.publicKey,
       globalState: await keys.getGlobalStateKey(),
-      weekResult: await keys.getWeekResultKey(week),
+      vesting: vestingKey,
+      vestingVault: await getAssociatedTokenAddress(accts.rankMint, vestingKey, true),
+      userFeelAta: await getAssociatedTokenAddress(accts.rankMint, user.publicKey),
+      rankMint: accts.rankMint,
+      feelTokenProgram: TOKEN_PROGRAM_ID,
+      associatedTokenProgram: ASSOCIATED_TOKEN_PROGRAM_ID,
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
     })
-    .signers([admin.keypair])
+    .signers([user.keypair])
     .transaction(),
-    [admin.keypair],
+    [user.keypair],
     connection
   );
-
-  let pastBal = (await connection.getBalance(admin.publicKey));
-  console.log("Sol change: ", (pastBal - preBal) / LAMPORTS_PER_SOL);
-
-}
+};
 
 export const closeEightBoxState = async (
   admin: User,
@@ -1323,9 +1831,10 @@ export const closeEightBoxState = async (
   await sendOrSimulateTransaction(await program.methods
     .closeEightBoxState()
     .accounts({
-      authority: admin.publicKey,
+      treasurer: admin.publicKey,
       globalState: await keys.getGlobalStateKey(),
       eightBoxState: boxStateKey,
+      eightBoxConfig: await keys.getEightBoxConfigKey(),
       systemProgram: SystemProgram.programId,
       rent: SYSVAR_RENT_PUBKEY,
     })
@@ -1362,22 +1871,22 @@ export const fetchUserBetState = async (
   return await fetchData("userBetState", key);
 };
 
-export const fetchHourState = async (
+export const fetchPeriodConfig = async (
   key: PublicKey
-): Promise<IdlAccounts<Betting>["hourState"] | null> => {
-  return await fetchData("hourState", key);
+): Promise<IdlAccounts<Betting>["periodConfig"] | null> => {
+  return await fetchData("periodConfig", key);
 };
 
-export const fetchDayState = async (
+export const fetchPeriodState = async (
   key: PublicKey
-): Promise<IdlAccounts<Betting>["dayState"] | null> => {
-  return await fetchData("dayState", key);
+): Promise<IdlAccounts<Betting>["periodState"] | null> => {
+  return await fetchData("periodState", key);
 };
 
-export const fetchWeekState = async (
+export const fetchPeriodResult = async (
   key: PublicKey
-): Promise<IdlAccounts<Betting>["weekState"] | null> => {
-  return await fetchData("weekState", key);
+): Promise<IdlAccounts<Betting>["periodResult"] | null> => {
+  return await fetchData("periodResult", key);
 };
 
 export const fetchEightBoxState = async (
@@ -5,18 +5,27 @@ import {
   USER_STATE_SEED,
   ARENA_STATE_SEED,
   USER_BET_SEED,
-  HOUR_STATE_SEED,
-  DAY_STATE_SEED,
-  WEEK_STATE_SEED,
-  HOUR_RESULT_SEED,
-  DAY_RESULT_SEED,
-  WEEK_RESULT_SEED,
+  PERIOD_CONFIG_SEED,
+  PERIOD_STATE_SEED,
+  PERIOD_RESULT_SEED,
+  PERIOD_LEADERBOARD_SEED,
+  SEASON_SEED,
+  VESTING_SEED,
+  SEASON_STATE_SEED,
+  SEASON_LEADERBOARD_SEED,
   FRAGMENT_MINTER_SEED,
   NFT_MINTER_SEED,
   BUNDLE_MINTER_SEED,
   EIGHT_BOX_STATE_SEED,
+  EIGHT_BOX_CONFIG_SEED,
   MetadataProgramId,
-  NFT_BUILD_STATE_SEED
+  NFT_BUILD_STATE_SEED,
+  FEE_SPLIT_SEED,
+  BET_MINT_SEED,
+  UNWRAP_SEED,
+  RANK_CLAIMS_SEED,
+  LEGACY_STATE_SEEDS,
+  LEGACY_RESULT_SEEDS
 } from "./constants";
 import { asyncGetPda } from "./utils";
 import { getProgram } from "../program";
@@ -30,6 +39,30 @@ export const getGlobalStateKey = async () => {
   return globalStateKey;
 };
 
+export const getFeeSplitKey = async () => {
+  const [feeSplitKey] = await asyncGetPda(
+    [Buffer.from(FEE_SPLIT_SEED)],
+    program.programId
+  );
+  return feeSplitKey;
+};
+
+export const getBetMintKey = async (mint: PublicKey) => {
+  const [betMintKey] = await asyncGetPda(
+    [Buffer.from(BET_MINT_SEED), mint.toBuffer()],
+    program.programId
+  );
+  return betMintKey;
+};
+
+export const getUnwrapKey = async (userKey: PublicKey) => {
+  const [unwrapKey] = await asyncGetPda(
+    [Buffer.from(UNWRAP_SEED), userKey.toBuffer()],
+    program.programId
+  );
+  return unwrapKey;
+};
+
 export const getUserStateKey = async (userKey: PublicKey) => {
   const [userStateKey] = await asyncGetPda(
     [Buffer.from(USER_STATE_SEED), userKey.toBuffer()],
@@ -57,25 +90,25 @@ export const getUserBetStateKey = async (arenaId: number, userKey: PublicKey) =>
   return userBetStateKey;
 };
 
-export const getUserHourStateKey = async (userKey: PublicKey, hour: BN) => {
+export const getEightBoxConfigKey = async () => {
   const [key] = await asyncGetPda(
-    [Buffer.from(HOUR_STATE_SEED), userKey.toBuffer(), hour.toArrayLike(Buffer, "le", 8)],
+    [Buffer.from(EIGHT_BOX_CONFIG_SEED)],
     program.programId
   );
   return key;
 };
 
-export const getUserDayStateKey = async (userKey: PublicKey, day: BN) => {
+export const getPeriodConfigKey = async () => {
   const [key] = await asyncGetPda(
-    [Buffer.from(DAY_STATE_SEED), userKey.toBuffer(), day.toArrayLike(Buffer, "le", 8)],
+    [Buffer.from(PERIOD_CONFIG_SEED)],
     program.programId
   );
   return key;
 };
 
-export const getUserWeekStateKey = async (userKey: PublicKey, week: BN) => {
+export const getUserPeriodStateKey = async (kind: number, userKey: PublicKey, period: BN) => {
   const [key] = await asyncGetPda(
-    [Buffer.from(WEEK_STATE_SEED), userKey.toBuffer(), week.toArrayLike(Buffer, "le", 8)],
+    [Buffer.from(PERIOD_STATE_SEED), Buffer.from([kind]), userKey.toBuffer(), period.toArrayLike(Buffer, "le", 8)],
     program.programId
   );
   return key;
@@ -99,25 +132,73 @@ export const getEightBoxStateKey = async (userKey: PublicKey, box_id: BN) => {
 };
 
 
-export const getHourResultKey = async (hour: BN) => {
+export const getPeriodLeaderboardKey = async (kind: number, period: BN) => {
+  const [key] = await asyncGetPda(
+    [Buffer.from(PERIOD_LEADERBOARD_SEED), Buffer.from([kind]), period.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  return key;
+};
+
+export const getSeasonKey = async (seasonId: number) => {
+  const [key] = await asyncGetPda(
+    [Buffer.from(SEASON_SEED), new BN(seasonId).toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  return key;
+};
+
+export const getUserSeasonStateKey = async (seasonId: number, userKey: PublicKey) => {
+  const [key] = await asyncGetPda(
+    [Buffer.from(SEASON_STATE_SEED), new BN(seasonId).toArrayLike(Buffer, "le", 8), userKey.toBuffer()],
+    program.programId
+  );
+  return key;
+};
+
+export const getSeasonLeaderboardKey = async (seasonId: number) => {
+  const [key] = await asyncGetPda(
+    [Buffer.from(SEASON_LEADERBOARD_SEED), new BN(seasonId).toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  return key;
+};
+
+export const getRankClaimsKey = async (resultKey: PublicKey) => {
+  const [key] = await asyncGetPda(
+    [Buffer.from(RANK_CLAIMS_SEED), resultKey.toBuffer()],
+    program.programId
+  );
+  return key;
+};
+
+export const getPeriodResultKey = async (kind: number, period: BN) => {
+  const [key] = await asyncGetPda(
+    [Buffer.from(PERIOD_RESULT_SEED), Buffer.from([kind]), period.toArrayLike(Buffer, "le", 8)],
+    program.programId
+  );
+  return key;
+};
+
+export const getLegacyStateKey = async (legacyKind: number, userKey: PublicKey, period: BN) => {
   const [key] = await asyncGetPda(
-    [Buffer.from(HOUR_RESULT_SEED), hour.toArrayLike(Buffer, "le", 8)],
+    [Buffer.from(LEGACY_STATE_SEEDS[legacyKind]), userKey.toBuffer(), period.toArrayLike(Buffer, "le", 8)],
     program.programId
   );
   return key;
 };
 
-export const getDayResultKey = async (day: BN) => {
+export const getLegacyResultKey = async (legacyKind: number, period: BN) => {
   const [key] = await asyncGetPda(
-    [Buffer.from(DAY_RESULT_SEED), new BN(day).toArrayLike(Buffer, "le", 8)],
+    [Buffer.from(LEGACY_RESULT_SEEDS[legacyKind]), period.toArrayLike(Buffer, "le", 8)],
     program.programId
   );
   return key;
 };
 
-export const getWeekResultKey = async (week: BN) => {
+export const getVestingKey = async (periodResultKey: PublicKey, userKey: PublicKey) => {
   const [key] = await asyncGetPda(
-    [Buffer.from(WEEK_RESULT_SEED), new BN(week).toArrayLike(Buffer, "le", 8)],
+    [Buffer.from(VESTING_SEED), periodResultKey.toBuffer(), userKey.toBuffer()],
     program.programId
   );
   return key;
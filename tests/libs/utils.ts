@@ -23,6 +23,7 @@ import {
   getAssociatedTokenAddress,
 } from "@solana/spl-token";
 import BN from 'bn.js';
+import { keccak_256 } from "js-sha3";
 import {
   ONE_HOUR_MS, 
   ONE_DAY_MS,
@@ -153,6 +154,54 @@ export const getPassedWeeks = (val: number): BN => {
   return new BN(Math.floor(val / ONE_WEEK_MS));
 }
 
+// period of a kind at `val` ms, same as PeriodKind::period_index
+export const getPeriodIndex = (val: number, length: BN, epochOffset: BN): BN => {
+  return new BN(Math.floor(val / 1000)).sub(epochOffset).div(length);
+}
+
+// wait until the cluster clock passes `timestamp` in seconds
+export const waitForClusterTime = async (connection: Connection, timestamp: number) => {
+  while ((await connection.getBlockTime(await connection.getSlot())) <= timestamp) {
+    await delay(1000);
+  }
+};
+
+// leaf of a ranked user in a period result's Merkle tree, same as rank_leaf
+export const getRankLeaf = (index: number, user: PublicKey, rank: number, reward: BN): Buffer => {
+  return Buffer.from(keccak_256.arrayBuffer(Buffer.concat([
+    new BN(index).toArrayLike(Buffer, "le", 4),
+    user.toBuffer(),
+    new BN(rank).toArrayLike(Buffer, "le", 4),
+    reward.toArrayLike(Buffer, "le", 8),
+  ])));
+};
+
+// root and proofs of `leaves`, nodes hash their children in sorted order like verify_proof,
+// the last node of an odd level moves up as it is
+export const getMerkleTree = (leaves: Buffer[]): { root: Buffer, proofs: Buffer[][] } => {
+  const proofs: Buffer[][] = leaves.map(() => []);
+  const positions = leaves.map((_, i) => i);
+  let level = leaves;
+  while (level.length > 1) {
+    const next: Buffer[] = [];
+    for (let i = 0; i < level.length; i += 2) {
+      if (i + 1 == level.length) {
+        next.push(level[i]);
+        continue;
+      }
+      const pair = Buffer.compare(level[i], level[i + 1]) <= 0 ? [level[i], level[i + 1]] : [level[i + 1], level[i]];
+      next.push(Buffer.from(keccak_256.arrayBuffer(Buffer.concat(pair))));
+    }
+    for (let leaf = 0; leaf < leaves.length; leaf ++) {
+      const sibling = positions[leaf] ^ 1;
+      if (sibling < level.length) proofs[leaf].push(level[sibling]);
+      positions[leaf] >>= 1;
+    }
+    level = next;
+  }
+  return { root: level[0], proofs };
+};
+
 
 export function getTransactionSize(
   transaction: Transaction,